@@ -0,0 +1,129 @@
+//! Benchmarks every quest part, with `cargo bench -p benches`.
+//!
+//! Parts are measured on the stored notes when there are some, otherwise on
+//! generated notes about the size of real ones, or the published examples
+//! for quests without a generator. The slowest functions are also measured
+//! on generated inputs large enough to show how they scale.
+//!
+//! Criterion keeps its reports in `target/criterion`. To compare a branch
+//! with `main`:
+//!
+//! ```text
+//! git checkout main && cargo bench -p benches -- --save-baseline main
+//! git checkout my-branch && cargo bench -p benches -- --baseline main
+//! ```
+
+use common::{Part, Rng, Solver, Store, fixtures, normalize, store::INPUTS_VAR};
+use criterion::{Criterion, criterion_group, criterion_main};
+use quest06::Quest06;
+use quest08::Quest08;
+use quest09::Quest09;
+use std::{env, hint::black_box, path::Path};
+
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("benches is a workspace member")
+}
+
+// Benches run from their own directory, so the default `inputs` store is
+// looked up at the root of the workspace.
+fn store() -> Store {
+    let store = Store::from_env();
+
+    match env::var_os(INPUTS_VAR) {
+        Some(_) => store,
+        None => Store::new(workspace().join("inputs"), store.event()),
+    }
+}
+
+fn input<S: Solver>(store: &Store, rng: &mut Rng, part: Part, size: usize) -> Option<String> {
+    if let Ok(Some(input)) = store.read(S::QUEST, part) {
+        return Some(normalize(&input));
+    }
+
+    if let Some(notes) = S::generate(rng, size) {
+        return Some(notes);
+    }
+
+    let dir = workspace()
+        .join(format!("quest{:02}", S::QUEST))
+        .join("fixtures");
+
+    fixtures::discover(&dir)
+        .ok()?
+        .into_iter()
+        .find(|f| f.part == part)
+        .map(|f| normalize(&f.input))
+}
+
+// `sizes` are those of the generated notes of each part, quests without a
+// generator ignore them.
+fn quest<S: Solver>(c: &mut Criterion, sizes: [usize; 3]) {
+    let store = store();
+    let mut rng = Rng::new(2025);
+    let mut group = c.benchmark_group(format!("quest{:02}", S::QUEST));
+    // the slowest parts take a good fraction of a second
+    group.sample_size(10);
+
+    for (part, size) in Part::ALL.into_iter().zip(sizes) {
+        let Some(input) = input::<S>(&store, &mut rng, part, size) else {
+            continue;
+        };
+
+        let parsed = S::parse(&input).unwrap_or_else(|e| panic!("{}", e.with_quest(S::QUEST)));
+
+        group.bench_function(format!("parse_{}", part.number()), |b| {
+            b.iter(|| S::parse(black_box(&input)))
+        });
+        group.bench_function(format!("part_{}", part.number()), |b| {
+            b.iter(|| S::solve(black_box(&parsed), part))
+        });
+    }
+
+    group.finish();
+}
+
+fn quests(c: &mut Criterion) {
+    quest::<quest01::Quest01>(c, [10, 10, 20]);
+    quest::<quest02::Quest02>(c, [0, 0, 0]);
+    quest::<quest03::Quest03>(c, [20, 300, 300]);
+    quest::<quest04::Quest04>(c, [10, 10, 40]);
+    quest::<quest05::Quest05>(c, [1, 100, 500]);
+    quest::<Quest06>(c, [300, 1000, 1000]);
+    quest::<quest07::Quest07>(c, [10, 50, 50]);
+    quest::<Quest08>(c, [1000, 1000, 300]);
+    quest::<Quest09>(c, [3, 200, 200]);
+}
+
+// Generated notes, parsed by `S`.
+fn generated<S: Solver>(rng: &mut Rng, size: usize) -> S::Input {
+    let notes = S::generate(rng, size).expect("quest has a generator");
+    S::parse(&notes).unwrap_or_else(|e| panic!("{}", e.with_quest(S::QUEST)))
+}
+
+fn synthetic(c: &mut Criterion) {
+    let mut rng = Rng::new(2025);
+    let mut group = c.benchmark_group("synthetic");
+    group.sample_size(10);
+
+    let threads = generated::<Quest08>(&mut rng, 100);
+    group.bench_function("quest08::find_max_cuts", |b| {
+        b.iter(|| quest08::find_max_cuts(black_box(&threads), 256))
+    });
+
+    let threads = generated::<Quest08>(&mut rng, 2000);
+    group.bench_function("quest08::count_knots", |b| {
+        b.iter(|| quest08::count_knots(black_box(&threads), 256))
+    });
+
+    let everyone = generated::<Quest09>(&mut rng, 100);
+    group.bench_function("quest09::find_parents", |b| {
+        b.iter(|| quest09::find_parents(black_box(&everyone[0]), &everyone))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, quests, synthetic);
+criterion_main!(benches);
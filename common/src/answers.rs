@@ -0,0 +1,106 @@
+use crate::Part;
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Toml(toml::de::Error),
+    Invalid(String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Io(path, err) => {
+                write!(f, "failed to read `{}`: {}", path.display(), err)
+            }
+            AnswersError::Toml(err) => write!(f, "invalid answers file: {}", err),
+            AnswersError::Invalid(message) => write!(f, "invalid answers file: {}", message),
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+/// Accepted answers, read from a TOML file with one table per quest:
+///
+/// ```toml
+/// [quest07]
+/// part1 = "Oroneth"
+/// part2 = 23
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+fn parse_quest(key: &str) -> Option<u8> {
+    key.strip_prefix("quest")?.parse().ok()
+}
+
+fn parse_part(key: &str) -> Option<Part> {
+    key.strip_prefix("part")?.parse().ok()
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, AnswersError> {
+        let table = input.parse::<toml::Table>().map_err(AnswersError::Toml)?;
+        let mut answers = Answers::default();
+
+        for (key, parts) in table {
+            let quest = parse_quest(&key)
+                .ok_or_else(|| AnswersError::Invalid(format!("unknown table `{}`", key)))?;
+
+            let parts = parts.as_table().ok_or_else(|| {
+                AnswersError::Invalid(format!("`{}` should be a table of parts", key))
+            })?;
+
+            for (name, answer) in parts {
+                let part = parse_part(name).ok_or_else(|| {
+                    AnswersError::Invalid(format!("unknown part `{}.{}`", key, name))
+                })?;
+
+                let answer = match answer {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(i) => i.to_string(),
+                    _ => {
+                        return Err(AnswersError::Invalid(format!(
+                            "`{}.{}` should be a string or an integer",
+                            key, name
+                        )));
+                    }
+                };
+
+                answers.insert(quest, part, answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    /// Reads an answers file, a missing file has no answers.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(input) => Answers::parse(&input),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(AnswersError::Io(path.to_path_buf(), err)),
+        }
+    }
+
+    pub fn get(&self, quest: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(quest, part)).map(|a| a.as_str())
+    }
+
+    pub fn insert(&mut self, quest: u8, part: Part, answer: impl Into<String>) {
+        self.answers.insert((quest, part), answer.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
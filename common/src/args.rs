@@ -0,0 +1,177 @@
+use crate::{Part, normalize, output::Report, stdin};
+use std::{env, error::Error, fmt, fs, io, path::PathBuf, process};
+
+#[derive(Debug)]
+pub enum ArgsError {
+    /// `--help` was requested, not really an error but it stops parsing.
+    Help,
+    MissingPart,
+    InvalidPart(String),
+    MissingValue(String),
+    InvalidValue(String, String),
+    Unexpected(String),
+    Input(PathBuf, io::Error),
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgsError::Help => write!(f, "help requested"),
+            ArgsError::MissingPart => write!(f, "part is required"),
+            ArgsError::InvalidPart(part) => {
+                write!(f, "invalid part `{}`, expected 1, 2 or 3", part)
+            }
+            ArgsError::MissingValue(flag) => write!(f, "`{}` requires a value", flag),
+            ArgsError::InvalidValue(flag, value) => {
+                write!(f, "invalid value `{}` for `{}`", value, flag)
+            }
+            ArgsError::Unexpected(arg) => write!(f, "unexpected argument `{}`", arg),
+            ArgsError::Input(path, err) => {
+                write!(f, "failed to read `{}`: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl Error for ArgsError {}
+
+/// Flags shared by every quest binary and the `ec` runner. Nothing is read
+/// yet, callers decide where the input comes from when `input` is unset.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    /// Hand the notes to the solver as they are, without [`normalize`].
+    pub raw: bool,
+    pub report: Report,
+}
+
+impl Options {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, ArgsError> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once("=") {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+                _ => (arg.as_str(), None),
+            };
+
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| ArgsError::MissingValue(flag.to_string()))
+            };
+
+            match flag {
+                "-h" | "--help" => return Err(ArgsError::Help),
+                "-p" | "--part" => options.part = Some(value()?.parse()?),
+                "-i" | "--input" => options.input = Some(PathBuf::from(value()?)),
+                "-t" | "--time" => options.report.time = true,
+                "--raw" => options.raw = true,
+                "-f" | "--format" => options.report.format = value()?.parse()?,
+                "-b" | "--bench" => {
+                    let runs = value()?;
+                    options.report.bench = match runs.parse() {
+                        Ok(0) | Err(_) => {
+                            return Err(ArgsError::InvalidValue(flag.to_string(), runs));
+                        }
+                        Ok(runs) => Some(runs),
+                    };
+                }
+                // the original `questNN part_N` spelling
+                _ if !flag.starts_with("-") && options.part.is_none() => {
+                    options.part = Some(flag.parse()?)
+                }
+                _ => return Err(ArgsError::Unexpected(arg)),
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Reads the input file if one was given, stdin otherwise.
+    pub fn read_input(&self) -> Result<String, ArgsError> {
+        let input = match &self.input {
+            Some(path) => {
+                fs::read_to_string(path).map_err(|e| ArgsError::Input(path.clone(), e))?
+            }
+            None => stdin(),
+        };
+
+        Ok(self.prepare(input))
+    }
+
+    /// Normalizes the notes unless `--raw` was given.
+    pub fn prepare(&self, input: String) -> String {
+        match self.raw {
+            true => input,
+            false => normalize(&input),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Args {
+    pub input: String,
+    pub part: Part,
+    pub report: Report,
+}
+
+pub fn try_parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Args, ArgsError> {
+    let options = Options::parse(args)?;
+    let part = options.part.ok_or(ArgsError::MissingPart)?;
+    let input = options.read_input()?;
+
+    Ok(Args {
+        input,
+        part,
+        report: options.report,
+    })
+}
+
+fn usage() -> String {
+    let program = env::args()
+        .next()
+        .and_then(|p| {
+            PathBuf::from(p)
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| "questNN".to_string());
+
+    format!(
+        "usage: {} --part <1|2|3> [--input <path>] [--time | --bench <runs>] [--format <text|json>] [--raw]
+
+options:
+    -p, --part <1|2|3>    part to solve, `part_1`, `part_2` and `part_3` also work
+    -i, --input <path>    read the notes from a file instead of stdin
+    -t, --time            print how long parsing and solving took
+    -b, --bench <runs>    solve the part <runs> times and print statistics
+    -f, --format <fmt>    `text` (default) or `json` records with the timings
+        --raw             don't strip the BOM, CRLF line endings and trailing
+                          whitespace from the notes
+    -h, --help            print this help
+
+environment:
+    EC_MEMO_STATS         when set, memoized solvers print their cache hits
+                          and misses to stderr",
+        program
+    )
+}
+
+/// Parses the process arguments, printing the usage and exiting on error.
+pub fn parse_args() -> Args {
+    match try_parse_args(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(ArgsError::Help) => {
+            println!("{}", usage());
+            process::exit(0);
+        }
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, usage());
+            process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use aes::cipher::{BlockDecryptMut, KeyIvInit, block_padding::Pkcs7};
+use std::{error::Error, fmt};
+
+type Decryptor = cbc::Decryptor<aes::Aes256>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecryptError {
+    InvalidHex,
+    /// Keys are 32 characters long, the first 16 being the IV.
+    InvalidKey(usize),
+    /// Usually means the key belongs to another part or quest.
+    InvalidPadding,
+    InvalidUtf8,
+}
+
+impl fmt::Display for DecryptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecryptError::InvalidHex => write!(f, "note is not valid hex"),
+            DecryptError::InvalidKey(len) => {
+                write!(f, "key should be 32 bytes long, got {}", len)
+            }
+            DecryptError::InvalidPadding => write!(f, "wrong key, padding is invalid"),
+            DecryptError::InvalidUtf8 => write!(f, "decrypted note is not valid utf-8"),
+        }
+    }
+}
+
+impl Error for DecryptError {}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>, DecryptError> {
+    let hex = hex.trim().as_bytes();
+
+    if !hex.len().is_multiple_of(2) {
+        return Err(DecryptError::InvalidHex);
+    }
+
+    hex.chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or(DecryptError::InvalidHex)
+        })
+        .collect()
+}
+
+/// Decrypts a note as served by everybody.codes: hex encoded AES-256-CBC
+/// with PKCS#7 padding, keyed by the part key and using its first 16 bytes
+/// as the IV.
+pub fn decrypt(note: &str, key: &str) -> Result<String, DecryptError> {
+    let key = key.as_bytes();
+
+    if key.len() != 32 {
+        return Err(DecryptError::InvalidKey(key.len()));
+    }
+
+    let mut buffer = decode_hex(note)?;
+
+    let plain = Decryptor::new(key.into(), key[..16].into())
+        .decrypt_padded_mut::<Pkcs7>(&mut buffer)
+        .map_err(|_| DecryptError::InvalidPadding)?;
+
+    String::from_utf8(plain.to_vec()).map_err(|_| DecryptError::InvalidUtf8)
+}
+
+/// A known key and note, for tests of anything handling notes.
+pub mod sample {
+    // generated with `openssl enc -aes-256-cbc -K <key> -iv <key[..16]>`
+    pub const KEY: &str = "Z9r!kP2@vQ7#mX4$wL8%tB1^hN5&jC3*";
+    pub const NOTE: &str = "a025804a8f7f549964ef201807b53d37";
+    /// What [`NOTE`] decrypts to with [`KEY`].
+    pub const PLAIN: &str = "ABabACacBCbca";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sample::{KEY, NOTE, PLAIN};
+
+    #[test]
+    fn decrypts_notes() {
+        assert_eq!(decrypt(NOTE, KEY), Ok(PLAIN.to_string()));
+    }
+
+    #[test]
+    fn rejects_wrong_keys() {
+        assert_eq!(decrypt(NOTE, "short"), Err(DecryptError::InvalidKey(5)));
+        assert_eq!(decrypt("abc", KEY), Err(DecryptError::InvalidHex));
+        assert!(decrypt(NOTE, "00000000000000000000000000000000").is_err());
+    }
+}
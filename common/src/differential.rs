@@ -0,0 +1,191 @@
+//! Differential testing: solves generated notes with a quest and with its
+//! [`Solver::reference`](crate::Solver::reference), then shrinks the first
+//! notes they disagree on.
+
+use crate::{Comparison, Part, Quest, panics};
+use std::{mem, ops::Range};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    Different {
+        answer: String,
+        reference: String,
+    },
+    /// Either side panicked, with this message.
+    Panic(String),
+}
+
+/// Generated notes the quest and its reference disagree on.
+#[derive(Clone, Debug)]
+pub struct Mismatch {
+    pub seed: u64,
+    /// Smallest notes found that still disagree.
+    pub notes: String,
+    pub failure: Failure,
+}
+
+/// Notes that don't parse or have no reference are not failures. Panics are
+/// caught without being printed.
+pub fn check(quest: &dyn Quest, notes: &str, part: Part) -> Option<Failure> {
+    match panics::catch(|| quest.compare(notes, part)) {
+        Ok(Ok(Comparison::Different { answer, reference })) => {
+            Some(Failure::Different { answer, reference })
+        }
+        Ok(_) => None,
+        Err(panic) => Some(Failure::Panic(panic.message)),
+    }
+}
+
+// Removes chunks of items as long as they stay interesting, halving the
+// chunk size down to single items.
+fn reduce<T: Clone>(mut items: Vec<T>, interesting: impl Fn(&[T]) -> bool) -> Vec<T> {
+    let mut chunk = (items.len() / 2).max(1);
+
+    loop {
+        let mut start = 0;
+
+        while start < items.len() {
+            let end = (start + chunk).min(items.len());
+            let candidate = [&items[..start], &items[end..]].concat();
+
+            if interesting(&candidate) {
+                items = candidate;
+            } else {
+                start += chunk;
+            }
+        }
+
+        if chunk == 1 {
+            return items;
+        }
+
+        chunk /= 2;
+    }
+}
+
+/// Shrinks `notes` while `interesting` holds: first whole lines, then the
+/// comma separated items of each line, or its characters if it has none.
+pub fn minimize(notes: &str, interesting: impl Fn(&str) -> bool) -> String {
+    let mut lines = reduce(notes.lines().map(String::from).collect(), |lines| {
+        interesting(&lines.join("\n"))
+    });
+
+    for i in 0..lines.len() {
+        let separator = if lines[i].contains(',') { "," } else { "" };
+
+        let items = match separator {
+            "," => lines[i].split(',').map(String::from).collect(),
+            _ => lines[i].chars().map(String::from).collect(),
+        };
+
+        let items = reduce(items, |items| {
+            let mut candidate = lines.clone();
+            candidate[i] = items.join(separator);
+            interesting(&candidate.join("\n"))
+        });
+
+        lines[i] = items.join(separator);
+    }
+
+    lines.join("\n")
+}
+
+/// Compares a part on the notes generated for every seed of `seeds`,
+/// stopping at the first disagreement.
+pub fn run(quest: &dyn Quest, part: Part, seeds: Range<u64>, size: usize) -> Option<Mismatch> {
+    seeds
+        .filter_map(|seed| Some((seed, quest.generate(seed, size)?)))
+        .find_map(|(seed, notes)| Some((seed, check(quest, &notes, part)?, notes)))
+        .map(|(seed, failure, notes)| {
+            // keep shrinking towards the same kind of failure, a wrong answer
+            // shouldn't turn into an unrelated panic
+            let notes = minimize(&notes, |notes| {
+                check(quest, notes, part)
+                    .is_some_and(|f| mem::discriminant(&f) == mem::discriminant(&failure))
+            });
+
+            Mismatch {
+                seed,
+                failure: check(quest, &notes, part).unwrap_or(failure),
+                notes,
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, Rng, Solver, entry};
+    use std::fmt::Display;
+
+    /// Sums numbers, forgetting the ones above 50 and choking on 7.
+    struct Buggy;
+
+    impl Solver for Buggy {
+        const QUEST: u8 = 99;
+
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            input
+                .split(',')
+                .map(|n| n.parse().map_err(|_| ParseError::new(input, n, "nope")))
+                .collect()
+        }
+
+        fn part_1(numbers: &Vec<u32>) -> impl Display {
+            numbers.iter().filter(|&&n| n <= 50).sum::<u32>()
+        }
+
+        fn part_2(numbers: &Vec<u32>) -> impl Display {
+            assert!(!numbers.contains(&7), "seven");
+            numbers.iter().sum::<u32>()
+        }
+
+        fn part_3(_: &Vec<u32>) -> impl Display {
+            0
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+            let numbers = (0..size)
+                .map(|_| rng.range(0..=99).to_string())
+                .collect::<Vec<_>>();
+
+            Some(numbers.join(","))
+        }
+
+        fn reference(part: Part) -> Option<fn(&Vec<u32>) -> String> {
+            match part {
+                Part::Part3 => None,
+                _ => Some(|numbers| numbers.iter().sum::<u32>().to_string()),
+            }
+        }
+    }
+
+    #[test]
+    fn minimizes_wrong_answers() {
+        let quest = entry::<Buggy>();
+        let mismatch = run(quest.as_ref(), Part::Part1, 0..10, 30).unwrap();
+
+        assert!(mismatch.notes.parse::<u32>().unwrap() > 50);
+        assert!(matches!(mismatch.failure, Failure::Different { .. }));
+    }
+
+    #[test]
+    fn catches_panics() {
+        let quest = entry::<Buggy>();
+        let mismatch = run(quest.as_ref(), Part::Part2, 0..10, 30).unwrap();
+
+        assert_eq!(mismatch.notes, "7");
+        assert_eq!(mismatch.failure, Failure::Panic("seven".to_string()));
+    }
+
+    #[test]
+    fn no_reference() {
+        let quest = entry::<Buggy>();
+
+        assert!(quest.has_reference(Part::Part1));
+        assert!(!quest.has_reference(Part::Part3));
+        assert!(run(quest.as_ref(), Part::Part3, 0..10, 30).is_none());
+    }
+}
@@ -0,0 +1,91 @@
+use std::{error::Error, fmt};
+
+/// Error returned by the quest parsers, pointing at the offending part of
+/// the notes so broken inputs can be fixed without guessing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by the runner, parsers don't need to know their quest.
+    pub quest: Option<u8>,
+    pub message: String,
+    /// 1-indexed line of the error.
+    pub line: usize,
+    /// 1-indexed column of the error, counted in characters.
+    pub column: usize,
+    /// Number of characters to underline, at least one.
+    pub width: usize,
+    /// The whole line the error is on.
+    pub snippet: String,
+}
+
+impl ParseError {
+    /// Points at `fragment`, which must be a slice of `input`. Fragments
+    /// coming from somewhere else are reported at the end of the input.
+    pub fn new(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let start = (fragment.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+
+        match start <= input.len() && start + fragment.len() <= input.len() {
+            true => Self::at(input, start, fragment.len(), message),
+            false => Self::at(input, input.len(), 0, message),
+        }
+    }
+
+    /// Points at `len` bytes starting at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, len: usize, message: impl Into<String>) -> Self {
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        let snippet = input[line_start..line_end].trim_end_matches('\r');
+        let underlined = &input[offset..(offset + len).min(line_end)];
+
+        ParseError {
+            quest: None,
+            message: message.into(),
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            width: underlined.chars().count().max(1),
+            snippet: snippet.to_string(),
+        }
+    }
+
+    pub fn with_quest(self, quest: u8) -> Self {
+        ParseError {
+            quest: Some(quest),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "{}", self.message)?;
+
+        match self.quest {
+            Some(quest) => writeln!(
+                f,
+                "{}--> quest {:02}, line {}, column {}",
+                gutter, quest, self.line, self.column
+            )?,
+            None => writeln!(
+                f,
+                "{}--> line {}, column {}",
+                gutter, self.line, self.column
+            )?,
+        }
+
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl Error for ParseError {}
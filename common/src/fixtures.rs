@@ -0,0 +1,160 @@
+use crate::{Part, Solver, normalize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// A published example: `partN.txt` holds the notes and `partN.expected`
+/// the answer. More examples for the same part go in `partN-<name>.txt`.
+#[derive(Clone, Debug)]
+pub struct Fixture {
+    pub path: PathBuf,
+    pub part: Part,
+    pub input: String,
+    pub expected: String,
+}
+
+// `part2` and `part2-large` are both fixtures of part 2.
+fn fixture_part(stem: &str) -> Option<Part> {
+    let part = stem.split_once('-').map_or(stem, |(part, _)| part);
+
+    match part {
+        "part1" => Some(Part::Part1),
+        "part2" => Some(Part::Part2),
+        "part3" => Some(Part::Part3),
+        _ => None,
+    }
+}
+
+/// Every fixture of `dir`, sorted by file name. A missing directory simply
+/// has no fixtures.
+pub fn discover(dir: &Path) -> io::Result<Vec<Fixture>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+
+    let mut fixtures = vec![];
+
+    for entry in entries {
+        let path = entry?.path();
+
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+
+        let Some(part) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(fixture_part)
+        else {
+            continue;
+        };
+
+        let expected = fs::read_to_string(path.with_extension("expected")).map_err(|err| {
+            io::Error::new(
+                err.kind(),
+                format!("missing expected answer for `{}`", path.display()),
+            )
+        })?;
+
+        fixtures.push(Fixture {
+            input: fs::read_to_string(&path)?,
+            expected: expected.trim_end().to_string(),
+            path,
+            part,
+        });
+    }
+
+    fixtures.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(fixtures)
+}
+
+/// Runs `S` on every fixture of `part` found in `dir`, panicking with all
+/// the mismatches at once, or when the part has no fixture at all.
+pub fn check<S: Solver>(dir: &Path, part: Part) {
+    let fixtures = discover(dir).unwrap_or_else(|err| panic!("{}", err));
+    let fixtures = fixtures
+        .iter()
+        .filter(|f| f.part == part)
+        .collect::<Vec<_>>();
+
+    if fixtures.is_empty() {
+        panic!(
+            "no fixture for part {} in `{}`",
+            part.number(),
+            dir.display()
+        );
+    }
+
+    let mut failures = vec![];
+
+    for fixture in fixtures {
+        let name = fixture.path.display();
+
+        match S::parse(&normalize(&fixture.input)) {
+            Ok(input) => {
+                let actual = S::solve(&input, part);
+
+                if actual != fixture.expected {
+                    failures.push(format!(
+                        "{}: expected `{}`, got `{}`",
+                        name, fixture.expected, actual
+                    ));
+                }
+            }
+            Err(err) => failures.push(format!("{}: {}", name, err.with_quest(S::QUEST))),
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!("\n{}\n", failures.join("\n"));
+    }
+}
+
+/// Generates one `#[test]` per part running the solver on the fixtures of
+/// the crate's `fixtures/` directory.
+#[macro_export]
+macro_rules! fixture_tests {
+    ($solver:ty) => {
+        #[cfg(test)]
+        mod fixtures {
+            use super::*;
+            use $crate::Part;
+
+            fn dir() -> ::std::path::PathBuf {
+                ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+            }
+
+            #[test]
+            fn part_1() {
+                $crate::fixtures::check::<$solver>(&dir(), Part::Part1);
+            }
+
+            #[test]
+            fn part_2() {
+                $crate::fixtures::check::<$solver>(&dir(), Part::Part2);
+            }
+
+            #[test]
+            fn part_3() {
+                $crate::fixtures::check::<$solver>(&dir(), Part::Part3);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixture_names() {
+        assert_eq!(fixture_part("part1"), Some(Part::Part1));
+        assert_eq!(fixture_part("part3-large"), Some(Part::Part3));
+        assert_eq!(fixture_part("part4"), None);
+        assert_eq!(fixture_part("notes"), None);
+    }
+}
@@ -0,0 +1,447 @@
+//! Adjacency list graphs over any hashable node, with the usual traversals.
+//! Nodes and edges keep their insertion order, so every traversal visits
+//! them in the same order from one run to the next.
+
+use std::{
+    borrow::Borrow,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    fmt::{Display, Write},
+    hash::Hash,
+};
+
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    directed: bool,
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, u64)>>,
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    /// A graph whose edges go one way.
+    pub fn directed() -> Self {
+        Graph {
+            directed: true,
+            nodes: vec![],
+            index: HashMap::new(),
+            edges: vec![],
+        }
+    }
+
+    /// A graph whose edges go both ways.
+    pub fn undirected() -> Self {
+        Graph {
+            directed: false,
+            ..Graph::directed()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn find<Q>(&self, node: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.get(node).copied()
+    }
+
+    fn insert(&mut self, node: N) -> usize {
+        if let Some(&i) = self.index.get(&node) {
+            return i;
+        }
+
+        self.index.insert(node.clone(), self.nodes.len());
+        self.nodes.push(node);
+        self.edges.push(vec![]);
+        self.nodes.len() - 1
+    }
+
+    /// Adds a node without edges, if it isn't there yet.
+    pub fn add_node(&mut self, node: N) {
+        self.insert(node);
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N) {
+        self.add_weighted_edge(from, to, 1);
+    }
+
+    /// Adds the missing nodes along with the edge. Adding an edge that's
+    /// already there only updates its weight.
+    pub fn add_weighted_edge(&mut self, from: N, to: N, weight: u64) {
+        let (a, b) = (self.insert(from), self.insert(to));
+
+        self.link(a, b, weight);
+
+        if !self.directed {
+            self.link(b, a, weight);
+        }
+    }
+
+    fn link(&mut self, a: usize, b: usize, weight: u64) {
+        match self.edges[a].iter_mut().find(|(to, _)| *to == b) {
+            Some(edge) => edge.1 = weight,
+            None => self.edges[a].push((b, weight)),
+        }
+    }
+
+    pub fn contains<Q>(&self, node: &Q) -> bool
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.contains_key(node)
+    }
+
+    pub fn has_edge<Q>(&self, from: &Q, to: &Q) -> bool
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match (self.find(from), self.find(to)) {
+            (Some(a), Some(b)) => self.edges[a].iter().any(|(to, _)| *to == b),
+            _ => false,
+        }
+    }
+
+    /// Every node, in insertion order.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// Every edge with its weight. Edges of an undirected graph come once.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N, u64)> {
+        self.edges.iter().enumerate().flat_map(move |(a, edges)| {
+            edges
+                .iter()
+                .filter(move |(b, _)| self.directed || a <= *b)
+                .map(move |&(b, weight)| (&self.nodes[a], &self.nodes[b], weight))
+        })
+    }
+
+    /// The nodes `node` has an edge to, none if it isn't in the graph.
+    pub fn neighbours<Q>(&self, node: &Q) -> impl Iterator<Item = &N>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(node)
+            .into_iter()
+            .flat_map(|i| &self.edges[i])
+            .map(|&(to, _)| &self.nodes[to])
+    }
+
+    /// Breadth first from `start`: the reachable nodes in the order they're
+    /// reached, each with its number of edges from `start`.
+    pub fn bfs<Q>(&self, start: &Q) -> Vec<(&N, usize)>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(start) = self.find(start) else {
+            return vec![];
+        };
+
+        let mut seen = vec![false; self.len()];
+        let mut queue = VecDeque::from([(start, 0)]);
+        let mut order = vec![];
+
+        seen[start] = true;
+
+        while let Some((i, depth)) = queue.pop_front() {
+            order.push((&self.nodes[i], depth));
+
+            for &(to, _) in &self.edges[i] {
+                if !seen[to] {
+                    seen[to] = true;
+                    queue.push_back((to, depth + 1));
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Depth first from `start`: the reachable nodes in preorder.
+    pub fn dfs<Q>(&self, start: &Q) -> Vec<&N>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(start) = self.find(start) else {
+            return vec![];
+        };
+
+        let mut seen = vec![false; self.len()];
+        let mut stack = vec![start];
+        let mut order = vec![];
+
+        while let Some(i) = stack.pop() {
+            if seen[i] {
+                continue;
+            }
+
+            seen[i] = true;
+            order.push(&self.nodes[i]);
+
+            // reversed so the first neighbour is visited first
+            stack.extend(self.edges[i].iter().rev().map(|&(to, _)| to));
+        }
+
+        order
+    }
+
+    /// Groups of nodes linked by edges, whatever their direction. Ordered by
+    /// their first node, which is the order of the nodes inside too.
+    pub fn components(&self) -> Vec<Vec<&N>> {
+        let mut roots = (0..self.len()).collect::<Vec<_>>();
+
+        fn root(roots: &mut [usize], mut i: usize) -> usize {
+            while roots[i] != i {
+                roots[i] = roots[roots[i]];
+                i = roots[i];
+            }
+
+            i
+        }
+
+        for (a, edges) in self.edges.iter().enumerate() {
+            for &(b, _) in edges {
+                let (ra, rb) = (root(&mut roots, a), root(&mut roots, b));
+                roots[ra.max(rb)] = ra.min(rb);
+            }
+        }
+
+        let mut components: Vec<Vec<&N>> = vec![];
+        let mut slots = HashMap::new();
+
+        for i in 0..self.len() {
+            let slot = *slots.entry(root(&mut roots, i)).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+
+            components[slot].push(&self.nodes[i]);
+        }
+
+        components
+    }
+
+    /// Orders the nodes so every edge goes forwards, picking the earliest
+    /// inserted node when there's a choice. `None` if there's a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<&N>> {
+        let mut incoming = vec![0; self.len()];
+
+        for &(to, _) in self.edges.iter().flatten() {
+            incoming[to] += 1;
+        }
+
+        let mut ready = (0..self.len())
+            .filter(|&i| incoming[i] == 0)
+            .map(Reverse)
+            .collect::<BinaryHeap<_>>();
+        let mut order = vec![];
+
+        while let Some(Reverse(i)) = ready.pop() {
+            order.push(&self.nodes[i]);
+
+            for &(to, _) in &self.edges[i] {
+                incoming[to] -= 1;
+
+                if incoming[to] == 0 {
+                    ready.push(Reverse(to));
+                }
+            }
+        }
+
+        (order.len() == self.len()).then_some(order)
+    }
+
+    // Dijkstra from `start`, the distance and previous node of each node.
+    fn dijkstra(&self, start: usize) -> Vec<Option<(u64, usize)>> {
+        let mut best: Vec<Option<(u64, usize)>> = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((0, start, start))]);
+
+        while let Some(Reverse((distance, i, previous))) = queue.pop() {
+            if best[i].is_some() {
+                continue;
+            }
+
+            best[i] = Some((distance, previous));
+
+            for &(to, weight) in &self.edges[i] {
+                if best[to].is_none() {
+                    queue.push(Reverse((distance + weight, to, i)));
+                }
+            }
+        }
+
+        best
+    }
+
+    /// The length of the shortest path from `start` to every reachable node.
+    pub fn shortest_paths<Q>(&self, start: &Q) -> HashMap<&N, u64>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(start) = self.find(start) else {
+            return HashMap::new();
+        };
+
+        self.dijkstra(start)
+            .iter()
+            .enumerate()
+            .filter_map(|(i, best)| Some((&self.nodes[i], best.as_ref()?.0)))
+            .collect()
+    }
+
+    /// The length of a shortest path from `start` to `end` and its nodes,
+    /// both ends included.
+    pub fn shortest_path<Q>(&self, start: &Q, end: &Q) -> Option<(u64, Vec<&N>)>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (start, end) = (self.find(start)?, self.find(end)?);
+        let best = self.dijkstra(start);
+        let (distance, _) = best[end]?;
+        let mut path = vec![&self.nodes[end]];
+        let mut i = end;
+
+        while i != start {
+            i = best[i]?.1;
+            path.push(&self.nodes[i]);
+        }
+
+        path.reverse();
+
+        Some((distance, path))
+    }
+}
+
+impl<N: Clone + Eq + Hash + Display> Graph<N> {
+    /// Graphviz source, e.g. for `dot -Tsvg`. Weights other than 1 become
+    /// edge labels.
+    pub fn to_dot(&self) -> String {
+        let (kind, arrow) = match self.directed {
+            true => ("digraph", "->"),
+            false => ("graph", "--"),
+        };
+
+        let quote = |node: &N| format!("{:?}", node.to_string());
+        let mut dot = format!("{} {{\n", kind);
+
+        for node in &self.nodes {
+            writeln!(dot, "    {};", quote(node)).expect("writing to a string");
+        }
+
+        for (from, to, weight) in self.edges() {
+            write!(dot, "    {} {} {}", quote(from), arrow, quote(to))
+                .expect("writing to a string");
+
+            if weight != 1 {
+                write!(dot, " [label={}]", weight).expect("writing to a string");
+            }
+
+            dot.push_str(";\n");
+        }
+
+        dot.push('}');
+        dot
+    }
+}
+
+impl<N: Clone + Eq + Hash> FromIterator<(N, N)> for Graph<N> {
+    /// A directed graph with these edges.
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(edges: I) -> Self {
+        let mut graph = Graph::directed();
+
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters(nodes: Vec<&char>) -> String {
+        nodes.into_iter().collect()
+    }
+
+    #[test]
+    fn traversals() {
+        let graph = [('a', 'b'), ('a', 'c'), ('b', 'd'), ('c', 'd'), ('e', 'a')]
+            .into_iter()
+            .collect::<Graph<_>>();
+
+        let bfs = graph.bfs(&'a');
+        assert_eq!(letters(bfs.iter().map(|(n, _)| *n).collect()), "abcd");
+        assert_eq!(bfs.last(), Some(&(&'d', 2)));
+        assert_eq!(letters(graph.dfs(&'e')), "eabdc");
+        assert_eq!(letters(graph.topological_sort().unwrap()), "eabcd");
+        assert!(graph.has_edge(&'e', &'a') && !graph.has_edge(&'a', &'e'));
+
+        let mut cyclic = graph.clone();
+        cyclic.add_edge('d', 'e');
+        assert_eq!(cyclic.topological_sort(), None);
+    }
+
+    #[test]
+    fn components() {
+        let mut graph = Graph::undirected();
+        graph.add_edge(1, 4);
+        graph.add_edge(2, 3);
+        graph.add_node(5);
+        graph.add_edge(4, 6);
+
+        assert_eq!(
+            graph.components(),
+            vec![vec![&1, &4, &6], vec![&2, &3], vec![&5]]
+        );
+        assert_eq!(graph.neighbours(&4).collect::<Vec<_>>(), vec![&1, &6]);
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let mut graph = Graph::directed();
+        graph.add_weighted_edge("a", "b", 7);
+        graph.add_weighted_edge("a", "c", 2);
+        graph.add_weighted_edge("c", "b", 3);
+        graph.add_weighted_edge("b", "d", 1);
+
+        assert_eq!(
+            graph.shortest_path(&"a", &"d"),
+            Some((6, vec![&"a", &"c", &"b", &"d"]))
+        );
+        assert_eq!(graph.shortest_path(&"d", &"a"), None);
+        assert_eq!(graph.shortest_paths(&"c").get(&"d"), Some(&4));
+    }
+
+    #[test]
+    fn dot() {
+        let mut graph = Graph::undirected();
+        graph.add_edge("x", "y");
+        graph.add_weighted_edge("y", "z", 3);
+
+        assert_eq!(
+            graph.to_dot(),
+            "graph {\n    \"x\";\n    \"y\";\n    \"z\";\n    \"x\" -- \"y\";\n    \"y\" -- \"z\" [label=3];\n}"
+        );
+    }
+}
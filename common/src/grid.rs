@@ -0,0 +1,456 @@
+//! 2D grids: [`Grid`] stores every cell of a rectangle, [`Sparse`] only the
+//! cells that are set, for layouts that are mostly empty or unbounded.
+
+use crate::ParseError;
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    ops::{Add, Index, IndexMut},
+};
+
+/// A cell position, `y` grows downwards. Signed so stepping off the edge
+/// gives a position that simply isn't in the grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Pos {
+    pub const UP: Pos = Pos::new(0, -1);
+    pub const RIGHT: Pos = Pos::new(1, 0);
+    pub const DOWN: Pos = Pos::new(0, 1);
+    pub const LEFT: Pos = Pos::new(-1, 0);
+
+    /// Up, right, down, left.
+    pub const ORTHOGONAL: [Pos; 4] = [Pos::UP, Pos::RIGHT, Pos::DOWN, Pos::LEFT];
+
+    /// Clockwise from up, diagonals included.
+    pub const ALL: [Pos; 8] = [
+        Pos::UP,
+        Pos::new(1, -1),
+        Pos::RIGHT,
+        Pos::new(1, 1),
+        Pos::DOWN,
+        Pos::new(-1, 1),
+        Pos::LEFT,
+        Pos::new(-1, -1),
+    ];
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Pos { x, y }
+    }
+
+    /// The 4 orthogonal neighbours.
+    pub fn neighbours(self) -> impl Iterator<Item = Pos> {
+        Pos::ORTHOGONAL.into_iter().map(move |d| self + d)
+    }
+
+    /// The 8 neighbours, diagonals included.
+    pub fn neighbours_8(self) -> impl Iterator<Item = Pos> {
+        Pos::ALL.into_iter().map(move |d| self + d)
+    }
+
+    pub fn manhattan(self, other: Pos) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Pos) -> Pos {
+        Pos::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+/// A dense grid, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Pos::new(x as i64, y as i64)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line with `cell`, which returns `None` for
+    /// characters it doesn't expect. Rows must all be the same length.
+    pub fn parse(input: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self, ParseError> {
+        let rows = input.lines().collect::<Vec<_>>();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        let mut cells = Vec::with_capacity(width * rows.len());
+
+        for row in &rows {
+            if row.chars().count() != width {
+                let message = format!("expected a row of {} cells", width);
+                return Err(ParseError::new(input, row, message));
+            }
+
+            for (i, c) in row.char_indices() {
+                let parsed = cell(c).ok_or_else(|| {
+                    ParseError::new(input, &row[i..i + c.len_utf8()], "unexpected cell")
+                })?;
+
+                cells.push(parsed);
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (0..self.width as i64).contains(&pos.x) && (0..self.height as i64).contains(&pos.y)
+    }
+
+    fn offset(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    /// The cell at `pos`, `None` outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::new(x as i64, y as i64)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbours().filter_map(|p| Some((p, self.get(p)?)))
+    }
+
+    /// Like [`Grid::neighbours`], diagonals included.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbours_8().filter_map(|p| Some((p, self.get(p)?)))
+    }
+
+    /// Row `y`, panics outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks of 0 aren't allowed, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Column `x` from top to bottom, panics outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Renders one line per row, without a final newline.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Pos::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate(&self) -> Grid<T> {
+        let bottom = self.height as i64 - 1;
+
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Pos::new(p.y, bottom - p.x)].clone()
+        })
+    }
+}
+
+impl Grid<char> {
+    /// Parses any rectangle of characters.
+    pub fn chars(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, Some)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{} outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{} outside the grid", pos))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A grid that only stores the cells that are set, at any position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sparse<T> {
+    cells: HashMap<Pos, T>,
+}
+
+impl<T> Default for Sparse<T> {
+    fn default() -> Self {
+        Sparse {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Sparse<T> {
+    pub fn new() -> Self {
+        Sparse::default()
+    }
+
+    /// Parses one row per line, keeping the cells `cell` returns something
+    /// for, e.g. the `#` of a `.`/`#` map.
+    pub fn parse(input: &str, cell: impl Fn(char) -> Option<T>) -> Self {
+        let cells = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .map(move |(x, c)| (Pos::new(x as i64, y as i64), c))
+            })
+            .filter_map(|(pos, c)| Some((pos, cell(c)?)))
+            .collect();
+
+        Sparse { cells }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Sets a cell, returning what was there.
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// The set cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// The set orthogonal neighbours of `pos`.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbours().filter_map(|p| Some((p, self.get(p)?)))
+    }
+
+    /// Like [`Sparse::neighbours`], diagonals included.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbours_8().filter_map(|p| Some((p, self.get(p)?)))
+    }
+
+    /// The top left and bottom right corners of the set cells.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        let xs = self.cells.keys().map(|p| p.x);
+        let ys = self.cells.keys().map(|p| p.y);
+
+        Some((
+            Pos::new(xs.clone().min()?, ys.clone().min()?),
+            Pos::new(xs.max()?, ys.max()?),
+        ))
+    }
+
+    /// The dense grid spanning [`Sparse::bounds`], with `cell` turning each
+    /// position's value (if set) into a cell. Its top left is the top left
+    /// bound.
+    pub fn to_grid<U>(&self, cell: impl Fn(Option<&T>) -> U) -> Grid<U> {
+        let Some((min, max)) = self.bounds() else {
+            return Grid {
+                width: 0,
+                height: 0,
+                cells: vec![],
+            };
+        };
+
+        let (width, height) = ((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize);
+
+        Grid::from_fn(width, height, |p| cell(self.get(p + min)))
+    }
+
+    /// Renders the bounds, `empty` where nothing is set.
+    pub fn render(&self, cell: impl Fn(&T) -> char, empty: char) -> String {
+        self.to_grid(|value| value.map_or(empty, &cell))
+            .render(|&c| c)
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for Sparse<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        Sparse {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#..\n.#.\n##.\n.#.";
+
+    #[test]
+    fn parses_and_renders() {
+        let grid = Grid::chars(MAP).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[Pos::new(1, 2)], '#');
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, -1)), None);
+        assert_eq!(grid.to_string(), MAP);
+
+        let err = Grid::chars("#..\n.#\n").unwrap_err();
+        assert_eq!(
+            (err.message.as_str(), err.line),
+            ("expected a row of 3 cells", 2)
+        );
+
+        let err = Grid::parse("#.\n.x", |c| (c != 'x').then_some(c)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::chars(MAP).unwrap();
+        let walls = |cells: Vec<(Pos, &char)>| cells.iter().filter(|(_, c)| **c == '#').count();
+
+        assert_eq!(grid.neighbours(Pos::new(0, 0)).count(), 2);
+        assert_eq!(walls(grid.neighbours(Pos::new(1, 1)).collect()), 1);
+        assert_eq!(walls(grid.neighbours_8(Pos::new(1, 1)).collect()), 3);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::chars(MAP).unwrap();
+
+        assert_eq!(grid.row(2), ['#', '#', '.']);
+        assert_eq!(grid.column(1).collect::<String>(), ".###");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.transpose().render(|&c| c), "#.#.\n.###\n....");
+        assert_eq!(grid.rotate().render(|&c| c), ".#.#\n###.\n....");
+        assert_eq!(grid.rotate().rotate().rotate().rotate(), grid);
+    }
+
+    #[test]
+    fn sparse() {
+        let mut walls = Sparse::parse(MAP, |c| (c == '#').then_some(()));
+
+        assert_eq!(walls.len(), 5);
+        assert_eq!(walls.neighbours_8(Pos::new(1, 2)).count(), 3);
+
+        walls.insert(Pos::new(-1, 5), ());
+        assert_eq!(walls.bounds(), Some((Pos::new(-1, 0), Pos::new(1, 5))));
+        assert_eq!(walls.render(|_| '#', '.'), ".#.\n..#\n.##\n..#\n...\n#..");
+    }
+}
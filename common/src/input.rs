@@ -0,0 +1,28 @@
+/// Cleans up notes saved by editors on other systems: drops a leading byte
+/// order mark, turns CRLF line endings into LF, trims trailing whitespace
+/// from every line and drops the trailing blank lines.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let lines = input.lines().map(str::trim_end).collect::<Vec<_>>();
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(0, |i| i + 1);
+
+    lines[..end].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_notes() {
+        assert_eq!(
+            normalize("\u{feff}Vyrdax,Drakzyph \r\n\r\nR3,L2\r\n\r\n"),
+            "Vyrdax,Drakzyph\n\nR3,L2"
+        );
+        assert_eq!(normalize("a\n\n  b\t\n"), "a\n\n  b");
+        assert_eq!(normalize("\n\n"), "");
+    }
+}
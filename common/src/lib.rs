@@ -1,36 +1,78 @@
-use std::{env, io};
+mod answers;
+mod args;
+pub mod crypto;
+pub mod differential;
+mod error;
+pub mod fixtures;
+pub mod graph;
+mod grid;
+mod input;
+pub mod memo;
+pub mod output;
+pub mod panics;
+pub mod parse;
+mod registry;
+mod rng;
+mod solver;
+pub mod store;
+pub mod timing;
+
+pub use answers::{Answers, AnswersError};
+pub use args::{Args, ArgsError, Options, parse_args, try_parse_args};
+pub use error::ParseError;
+pub use grid::{Grid, Pos, Sparse};
+pub use input::normalize;
+pub use output::{Format, Report, print_solution};
+pub use registry::{Comparison, Quest, Registry, entry};
+pub use rng::Rng;
+pub use solver::Solver;
+pub use store::{Store, StoreError};
+
+use std::{io, process, str::FromStr};
 
 pub fn stdin() -> String {
     io::read_to_string(io::stdin()).expect("failed to read input")
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Part {
     Part1,
     Part2,
     Part3,
 }
 
-#[derive(Clone, Debug)]
-pub struct Args {
-    pub input: String,
-    pub part: Part,
+impl Part {
+    pub const ALL: [Part; 3] = [Part::Part1, Part::Part2, Part::Part3];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::Part1 => 1,
+            Part::Part2 => 2,
+            Part::Part3 => 3,
+        }
+    }
 }
 
-pub fn parse_args() -> Args {
-    let args: Vec<String> = env::args().collect();
+impl FromStr for Part {
+    type Err = ArgsError;
 
-    let part = match args.get(1) {
-        Some(part) => match part.as_str() {
-            "part_1" => Part::Part1,
-            "part_2" => Part::Part2,
-            "part_3" => Part::Part3,
-            _ => panic!("invalid argument"),
-        },
-        None => panic!("part is required"),
-    };
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "part_1" => Ok(Part::Part1),
+            "2" | "part_2" => Ok(Part::Part2),
+            "3" | "part_3" => Ok(Part::Part3),
+            _ => Err(ArgsError::InvalidPart(s.to_string())),
+        }
+    }
+}
 
-    let input = stdin();
+/// Entry point shared by every quest binary: reads the arguments, parses
+/// the input with `S` and prints the answer for the requested part.
+pub fn run<S: Solver + 'static>() {
+    let args = parse_args();
 
-    Args { input, part }
+    if let Err(err) = print_solution(entry::<S>().as_ref(), &args.input, args.part, &args.report) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
 }
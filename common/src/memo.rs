@@ -0,0 +1,118 @@
+//! Memoization for recursive solvers. The cache key is spelled out at every
+//! call, so it's plain to see what a result depends on.
+
+use std::{collections::HashMap, env, fmt, hash::Hash};
+
+/// When set, every [`Memo`] prints its stats to stderr once it's dropped.
+pub const STATS_VAR: &str = "EC_MEMO_STATS";
+
+/// Hits and misses of a [`Memo`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} hits, {} misses", self.hits, self.misses)
+    }
+}
+
+/// Caches the results of a function by key:
+///
+/// ```
+/// # use common::memo::Memo;
+/// fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+///     memo.get(n, |memo| match n {
+///         0 | 1 => n,
+///         _ => fib(n - 1, memo) + fib(n - 2, memo),
+///     })
+/// }
+///
+/// assert_eq!(fib(90, &mut Memo::new("fib")), 2880067194370816120);
+/// ```
+///
+/// With [`STATS_VAR`] set, its stats are printed to stderr when it's
+/// dropped.
+#[derive(Debug)]
+pub struct Memo<K, V> {
+    name: &'static str,
+    cache: HashMap<K, V>,
+    stats: Stats,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    /// `name` identifies it in the printed stats.
+    pub fn new(name: &'static str) -> Self {
+        Memo {
+            name,
+            cache: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+
+    /// The cached result for `key`, or computes it with `f`, which gets the
+    /// memo back to recurse with.
+    pub fn get(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+
+        let value = f(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        if env::var_os(STATS_VAR).is_some() {
+            eprintln!(
+                "memo {}: {}, {} entries",
+                self.name,
+                self.stats,
+                self.cache.len()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(x: u64, y: u64, memo: &mut Memo<(u64, u64), u64>) -> u64 {
+        memo.get((x, y), |memo| match (x, y) {
+            (0, _) | (_, 0) => 1,
+            _ => paths(x - 1, y, memo) + paths(x, y - 1, memo),
+        })
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let mut memo = Memo::new("paths");
+
+        assert_eq!(paths(2, 2, &mut memo), 6);
+        assert_eq!(memo.stats(), Stats { hits: 1, misses: 8 });
+        assert_eq!(memo.len(), 8);
+
+        paths(2, 2, &mut memo);
+        assert_eq!(memo.stats().hits, 2);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    ArgsError, ParseError, Part, Quest,
+    panics::Panic,
+    timing::{Bench, Solution},
+};
+use serde_json::json;
+use std::{str::FromStr, time::Duration};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line, for scripts and dashboards.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ArgsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(ArgsError::InvalidValue(
+                "--format".to_string(),
+                s.to_string(),
+            )),
+        }
+    }
+}
+
+/// What to print along with the answers.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Report {
+    /// Print how long parsing and solving took.
+    pub time: bool,
+    /// Solve each part this many times and print statistics.
+    pub bench: Option<usize>,
+    pub format: Format,
+}
+
+// Dividing the nanoseconds keeps the printed value short, multiplying
+// seconds gives things like 0.0058579999999999995.
+fn ms(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1e6
+}
+
+pub fn solution_json(quest: u8, part: Part, solution: &Solution) -> String {
+    json!({
+        "quest": quest,
+        "part": part.number(),
+        "answer": solution.answer,
+        "parse_ms": ms(solution.parse),
+        "solve_ms": ms(solution.solve),
+    })
+    .to_string()
+}
+
+/// Medians go in `parse_ms` and `solve_ms` so bench records read like
+/// single runs.
+pub fn bench_json(quest: u8, part: Part, bench: &Bench) -> String {
+    json!({
+        "quest": quest,
+        "part": part.number(),
+        "answer": bench.answer,
+        "parse_ms": ms(bench.parse.median),
+        "solve_ms": ms(bench.solve.median),
+        "runs": bench.runs,
+        "solve_min_ms": ms(bench.solve.min),
+        "solve_mean_ms": ms(bench.solve.mean),
+        "solve_max_ms": ms(bench.solve.max),
+    })
+    .to_string()
+}
+
+pub fn error_json(quest: u8, part: Part, error: &str) -> String {
+    json!({ "quest": quest, "part": part.number(), "error": error }).to_string()
+}
+
+/// Like [`error_json`], with the panic's `location` when it's known.
+pub fn panic_json(quest: u8, part: Part, panic: &Panic) -> String {
+    let mut record = json!({ "quest": quest, "part": part.number(), "error": panic.message });
+
+    if let Some(location) = &panic.location {
+        record["location"] = json!(location);
+    }
+
+    record.to_string()
+}
+
+/// Solves a part and prints its answer in the requested format, with the
+/// timings when asked.
+pub fn print_solution(
+    quest: &dyn Quest,
+    input: &str,
+    part: Part,
+    report: &Report,
+) -> Result<(), ParseError> {
+    match (report.bench, report.format) {
+        (Some(runs), Format::Text) => {
+            let bench = quest.bench(input, part, runs)?;
+            println!("{}\n{}", bench.answer, bench.summary());
+        }
+        (Some(runs), Format::Json) => {
+            let bench = quest.bench(input, part, runs)?;
+            println!("{}", bench_json(quest.number(), part, &bench));
+        }
+        (None, Format::Text) => {
+            let solution = quest.run(input, part)?;
+            println!("{}", solution.answer);
+
+            if report.time {
+                println!("{}", solution.timing());
+            }
+        }
+        (None, Format::Json) => {
+            let solution = quest.run(input, part)?;
+            println!("{}", solution_json(quest.number(), part, &solution));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solution_record() {
+        let solution = Solution {
+            answer: "Oroneth".to_string(),
+            parse: Duration::from_micros(1500),
+            solve: Duration::from_millis(2),
+        };
+
+        assert_eq!(
+            solution_json(7, Part::Part1, &solution),
+            r#"{"quest":7,"part":1,"answer":"Oroneth","parse_ms":1.5,"solve_ms":2.0}"#
+        );
+    }
+
+    #[test]
+    fn panic_record() {
+        let panic = Panic {
+            message: "bad \"crate\"\n".to_string(),
+            location: Some("quest09/src/lib.rs:3:5".to_string()),
+        };
+
+        assert_eq!(
+            panic_json(9, Part::Part2, &panic),
+            r#"{"quest":9,"part":2,"error":"bad \"crate\"\n","location":"quest09/src/lib.rs:3:5"}"#
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Panics caught as values, with the place they were raised at, so one
+//! failing part doesn't take down everything running next to it.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+/// A caught panic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// `file:line:column`, when the panic said.
+    pub location: Option<String>,
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {}: {}", location, self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+/// The message a caught panic was raised with.
+pub fn message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// Panics inside `catch` are recorded quietly, other panics still go to the
+// previous hook. Installed once since hooks are global to the process.
+fn install() {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| match CATCHING.get() {
+            true => LOCATION.set(info.location().map(|l| l.to_string())),
+            false => previous(info),
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into an error instead of printing it.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+    install();
+
+    let outer = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(outer);
+
+    result.map_err(|payload| Panic {
+        message: message(payload),
+        location: LOCATION.take(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catches_message_and_location() {
+        let line = line!() + 1;
+        let panic = catch(|| panic!("boom {}", 7)).unwrap_err();
+
+        assert_eq!(panic.message, "boom 7");
+        assert_eq!(
+            panic.location.as_deref(),
+            Some(format!("common/src/panics.rs:{}:30", line).as_str())
+        );
+        assert_eq!(catch(|| 3), Ok(3));
+    }
+}
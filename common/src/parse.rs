@@ -0,0 +1,135 @@
+//! Helpers for the shapes quest notes keep coming in. They take the whole
+//! `input` along with the piece being parsed, which must be a slice of it,
+//! so errors point at the right place in the notes.
+
+use crate::ParseError;
+use std::str::FromStr;
+
+/// Parses a trimmed field, `what` names it in the error.
+pub fn field<T: FromStr>(input: &str, text: &str, what: &str) -> Result<T, ParseError> {
+    let text = text.trim();
+
+    text.parse()
+        .map_err(|_| ParseError::new(input, text, format!("failed to parse {}", what)))
+}
+
+/// Parses every `separator` separated field of `text`, e.g. `4,51,13`.
+pub fn list<T: FromStr>(
+    input: &str,
+    text: &str,
+    separator: &str,
+    what: &str,
+) -> Result<Vec<T>, ParseError> {
+    text.trim()
+        .split(separator)
+        .map(|item| field(input, item, what))
+        .collect()
+}
+
+/// Splits `text` in two around the first `separator`, like `id:payload` or
+/// `a|b`. `shape` shows what was expected in the error.
+pub fn pair<'a>(
+    input: &str,
+    text: &'a str,
+    separator: &str,
+    shape: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    let text = text.trim();
+
+    text.split_once(separator)
+        .map(|(a, b)| (a.trim(), b.trim()))
+        .ok_or_else(|| ParseError::new(input, text, format!("expected `{}`", shape)))
+}
+
+/// The trimmed lines of `text` that aren't blank.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().map(str::trim).filter(|line| !line.is_empty())
+}
+
+/// Blocks of lines separated by blank lines.
+pub fn sections(text: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let (mut start, mut offset) = (None, 0);
+
+    for line in text.split('\n') {
+        match (line.trim().is_empty(), start) {
+            (true, Some(s)) => {
+                sections.push(text[s..offset].trim_end());
+                start = None;
+            }
+            (false, None) => start = Some(offset),
+            _ => {}
+        }
+
+        offset += line.len() + 1;
+    }
+
+    if let Some(s) = start {
+        sections.push(text[s..].trim_end());
+    }
+
+    sections
+}
+
+/// Exactly `N` sections, like a header, a blank line and a body. `shape`
+/// describes them in the error.
+pub fn exact_sections<'a, const N: usize>(
+    input: &'a str,
+    shape: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let sections = sections(input);
+    let message = format!("expected {}", shape);
+
+    match sections.get(N) {
+        Some(extra) => Err(ParseError::new(input, extra, message)),
+        None => sections
+            .try_into()
+            .map_err(|_| ParseError::new(input, &input[input.len()..], message)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists() {
+        let input = "1, 5,2";
+
+        assert_eq!(list::<u32>(input, input, ",", "nail"), Ok(vec![1, 5, 2]));
+
+        let err = list::<u32>("1,x,2", "1,x,2", ",", "nail").unwrap_err();
+        assert_eq!(
+            (err.message.as_str(), err.column),
+            ("failed to parse nail", 3)
+        );
+    }
+
+    #[test]
+    fn pairs() {
+        let input = "7:ACGT\n8";
+        let line = &input[..6];
+
+        assert_eq!(pair(input, line, ":", "id:dna"), Ok(("7", "ACGT")));
+
+        let err = pair(input, &input[7..], ":", "id:dna").unwrap_err();
+        assert_eq!((err.message.as_str(), err.line), ("expected `id:dna`", 2));
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "a,b\n\n  \nR3,L2\nR1\n\n";
+
+        assert_eq!(sections(input), vec!["a,b", "R3,L2\nR1"]);
+        assert_eq!(exact_sections::<2>(input, "two"), Ok(["a,b", "R3,L2\nR1"]));
+
+        let err = exact_sections::<3>(input, "three sections").unwrap_err();
+        assert_eq!(
+            (err.message.as_str(), err.line),
+            ("expected three sections", 7)
+        );
+
+        let err = exact_sections::<1>(input, "one section").unwrap_err();
+        assert_eq!(err.line, 4);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    ParseError, Part, Rng, Solver,
+    timing::{self, Bench, Solution},
+};
+use std::{collections::BTreeMap, marker::PhantomData};
+
+/// Type erased view of a [`Solver`], so quests with different inputs can
+/// live side by side in a [`Registry`], and be shared between threads.
+pub trait Quest: Send + Sync {
+    fn number(&self) -> u8;
+
+    fn run(&self, input: &str, part: Part) -> Result<Solution, ParseError>;
+
+    fn solve(&self, input: &str, part: Part) -> Result<String, ParseError> {
+        self.run(input, part).map(|s| s.answer)
+    }
+
+    fn bench(&self, input: &str, part: Part, runs: usize) -> Result<Bench, ParseError> {
+        timing::bench(runs, || self.run(input, part))
+    }
+
+    /// See [`Solver::generate`], the same seed always gives the same notes.
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+
+    /// Whether a part has a [`Solver::reference`], without solving anything.
+    fn has_reference(&self, part: Part) -> bool;
+
+    /// Solves a part along with its [`Solver::reference`] answer, if any.
+    fn compare(&self, input: &str, part: Part) -> Result<Comparison, ParseError>;
+}
+
+/// A part's answer next to its reference answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    NoReference,
+    Same,
+    Different { answer: String, reference: String },
+}
+
+// Holds no `S`, so it's `Send` and `Sync` whatever the solver.
+struct Entry<S>(PhantomData<fn() -> S>);
+
+/// A single solver behind the [`Quest`] interface.
+pub fn entry<S: Solver + 'static>() -> Box<dyn Quest> {
+    Box::new(Entry::<S>(PhantomData))
+}
+
+impl<S: Solver> Quest for Entry<S> {
+    fn number(&self) -> u8 {
+        S::QUEST
+    }
+
+    fn run(&self, input: &str, part: Part) -> Result<Solution, ParseError> {
+        timing::timed::<S>(input, part)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+
+    fn has_reference(&self, part: Part) -> bool {
+        S::reference(part).is_some()
+    }
+
+    fn compare(&self, input: &str, part: Part) -> Result<Comparison, ParseError> {
+        let Some(reference) = S::reference(part) else {
+            return Ok(Comparison::NoReference);
+        };
+
+        let parsed = S::parse(input).map_err(|e| e.with_quest(S::QUEST))?;
+        let (answer, reference) = (S::solve(&parsed, part), reference(&parsed));
+
+        Ok(match answer == reference {
+            true => Comparison::Same,
+            false => Comparison::Different { answer, reference },
+        })
+    }
+}
+
+/// Every known quest, ordered by quest number.
+#[derive(Default)]
+pub struct Registry {
+    quests: BTreeMap<u8, Box<dyn Quest>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solver + 'static>(mut self) -> Self {
+        if self.quests.contains_key(&S::QUEST) {
+            panic!("quest {} registered twice", S::QUEST);
+        }
+
+        self.quests.insert(S::QUEST, entry::<S>());
+        self
+    }
+
+    pub fn get(&self, quest: u8) -> Option<&dyn Quest> {
+        self.quests.get(&quest).map(|q| q.as_ref())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Quest> {
+        self.quests.values().map(|q| q.as_ref())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{ParseError, Part, Rng};
+use std::fmt::Display;
+
+/// A quest solution. Implementors only describe how to parse their notes
+/// and how to answer each part, the runner takes care of the rest.
+pub trait Solver {
+    /// Quest number, as listed on everybody.codes.
+    const QUEST: u8;
+
+    /// Parsed representation of the quest notes, shared by all parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(input: &Self::Input) -> impl Display;
+
+    fn part_2(input: &Self::Input) -> impl Display;
+
+    fn part_3(input: &Self::Input) -> impl Display;
+
+    /// Random valid notes with about `size` entries, for stress tests. `None`
+    /// when the quest has no generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// Straightforward but slow solution of a part, which the real one is
+    /// checked against. `None` when the part has no reference.
+    fn reference(_part: Part) -> Option<fn(&Self::Input) -> String> {
+        None
+    }
+
+    fn solve(input: &Self::Input, part: Part) -> String {
+        match part {
+            Part::Part1 => Self::part_1(input).to_string(),
+            Part::Part2 => Self::part_2(input).to_string(),
+            Part::Part3 => Self::part_3(input).to_string(),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    Part,
+    crypto::{self, DecryptError},
+};
+use std::{
+    env,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Everybody Codes event the quests belong to.
+pub const EVENT: &str = "2025";
+
+/// Overrides the directory inputs are stored in, `inputs` by default.
+pub const INPUTS_VAR: &str = "EC_INPUTS";
+
+/// Overrides the event inputs are looked up for, [`EVENT`] by default.
+pub const EVENT_VAR: &str = "EC_EVENT";
+
+#[derive(Debug)]
+pub enum StoreError {
+    Io(PathBuf, io::Error),
+    Json(PathBuf, serde_json::Error),
+    Decrypt(PathBuf, DecryptError),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StoreError::Io(path, err) => write!(f, "failed to read `{}`: {}", path.display(), err),
+            StoreError::Json(path, err) => {
+                write!(f, "invalid json in `{}`: {}", path.display(), err)
+            }
+            StoreError::Decrypt(path, err) => {
+                write!(f, "failed to decrypt `{}`: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl Error for StoreError {}
+
+fn read_optional(path: &Path) -> Result<Option<String>, StoreError> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(StoreError::Io(path.to_path_buf(), err)),
+    }
+}
+
+// Looks up a string field of a downloaded JSON file, `None` if either the
+// file or the field is missing.
+fn read_field(path: &Path, field: &str) -> Result<Option<String>, StoreError> {
+    let Some(content) = read_optional(path)? else {
+        return Ok(None);
+    };
+
+    let json: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| StoreError::Json(path.to_path_buf(), e))?;
+
+    Ok(json.get(field).and_then(|v| v.as_str()).map(String::from))
+}
+
+/// Quest notes saved on disk, laid out as
+/// `<root>/<event>/questNN/partN.txt` next to `<root>/<event>/answers.toml`.
+///
+/// Notes can also be kept encrypted as downloaded from everybody.codes, in
+/// `questNN/notes.json` with the part keys in `questNN/keys.json`. They are
+/// decrypted when read if there is no plain `partN.txt`.
+#[derive(Clone, Debug)]
+pub struct Store {
+    root: PathBuf,
+    event: String,
+}
+
+impl Store {
+    pub fn new(root: impl Into<PathBuf>, event: impl Into<String>) -> Self {
+        Store {
+            root: root.into(),
+            event: event.into(),
+        }
+    }
+
+    /// Uses [`INPUTS_VAR`] and [`EVENT_VAR`] when set, the defaults otherwise.
+    pub fn from_env() -> Self {
+        Store::new(
+            env::var_os(INPUTS_VAR).unwrap_or_else(|| "inputs".into()),
+            env::var(EVENT_VAR).unwrap_or_else(|_| EVENT.to_string()),
+        )
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+
+    pub fn quest_dir(&self, quest: u8) -> PathBuf {
+        self.root
+            .join(&self.event)
+            .join(format!("quest{:02}", quest))
+    }
+
+    pub fn path(&self, quest: u8, part: Part) -> PathBuf {
+        self.quest_dir(quest)
+            .join(format!("part{}.txt", part.number()))
+    }
+
+    /// Accepted answers for the event, see [`crate::Answers`].
+    pub fn answers_path(&self) -> PathBuf {
+        self.root.join(&self.event).join("answers.toml")
+    }
+
+    /// Seed of the logged in user, which notes are generated from.
+    pub fn seed_path(&self) -> PathBuf {
+        self.root.join(&self.event).join("seed.txt")
+    }
+
+    /// Every answer submitted to everybody.codes and the verdict it got.
+    pub fn submissions_path(&self) -> PathBuf {
+        self.root.join(&self.event).join("submissions.log")
+    }
+
+    /// Encrypted notes of every part, keyed by part number.
+    pub fn notes_path(&self, quest: u8) -> PathBuf {
+        self.quest_dir(quest).join("notes.json")
+    }
+
+    /// Part keys, as `key1`, `key2` and `key3`.
+    pub fn keys_path(&self, quest: u8) -> PathBuf {
+        self.quest_dir(quest).join("keys.json")
+    }
+
+    /// Whether the notes of a part can be read, in plain text or encrypted
+    /// along with their key. Downloaded files that can't be read are an error
+    /// rather than missing notes.
+    pub fn contains(&self, quest: u8, part: Part) -> Result<bool, StoreError> {
+        Ok(self.path(quest, part).is_file() || self.encrypted(quest, part)?.is_some())
+    }
+
+    /// The encrypted note of a part and its key, if both were downloaded.
+    pub fn encrypted(&self, quest: u8, part: Part) -> Result<Option<(String, String)>, StoreError> {
+        let number = part.number();
+        let note = read_field(&self.notes_path(quest), &number.to_string())?;
+        let key = read_field(&self.keys_path(quest), &format!("key{}", number))?;
+
+        Ok(note.zip(key))
+    }
+
+    /// Reads the notes of a part, decrypting them if needed. `None` if they
+    /// were never saved or their key isn't known yet.
+    pub fn read(&self, quest: u8, part: Part) -> Result<Option<String>, StoreError> {
+        if let Some(input) = read_optional(&self.path(quest, part))? {
+            return Ok(Some(input));
+        }
+
+        match self.encrypted(quest, part)? {
+            Some((note, key)) => crypto::decrypt(&note, &key)
+                .map(Some)
+                .map_err(|e| StoreError::Decrypt(self.notes_path(quest), e)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn write(&self, quest: u8, part: Part, input: &str) -> io::Result<()> {
+        fs::create_dir_all(self.quest_dir(quest))?;
+        fs::write(self.path(quest, part), input)
+    }
+}
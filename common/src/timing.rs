@@ -0,0 +1,133 @@
+use crate::{ParseError, Part, Solver};
+use std::time::{Duration, Instant};
+
+/// An answer along with how long it took to get it.
+#[derive(Clone, Debug)]
+pub struct Solution {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Solution {
+    pub fn timing(&self) -> String {
+        format!(
+            "parse {}, solve {}",
+            format_duration(self.parse),
+            format_duration(self.solve)
+        )
+    }
+}
+
+pub fn timed<S: Solver>(input: &str, part: Part) -> Result<Solution, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|e| e.with_quest(S::QUEST))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = S::solve(&parsed, part);
+    let solve = start.elapsed();
+
+    Ok(Solution {
+        answer,
+        parse,
+        solve,
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics on an empty slice, there is nothing to summarize.
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let median = match sorted.len() {
+            0 => panic!("no samples"),
+            n if n % 2 == 0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            n => sorted[n / 2],
+        };
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / sorted.len() as u32,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// Timings of the same part solved several times in a row.
+#[derive(Clone, Debug)]
+pub struct Bench {
+    pub answer: String,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Bench {
+    pub fn summary(&self) -> String {
+        format!(
+            "parse median {}\nsolve min {}, median {}, mean {}, max {} ({} runs)",
+            format_duration(self.parse.median),
+            format_duration(self.solve.min),
+            format_duration(self.solve.median),
+            format_duration(self.solve.mean),
+            format_duration(self.solve.max),
+            self.runs
+        )
+    }
+}
+
+/// Calls `run` `runs` times, keeping the answer of the last run.
+pub fn bench(
+    runs: usize,
+    mut run: impl FnMut() -> Result<Solution, ParseError>,
+) -> Result<Bench, ParseError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+    let mut answer = String::new();
+
+    for _ in 0..runs.max(1) {
+        let solution = run()?;
+        parse.push(solution.parse);
+        solve.push(solution.solve);
+        answer = solution.answer;
+    }
+
+    Ok(Bench {
+        answer,
+        runs: parse.len(),
+        parse: Stats::new(&parse),
+        solve: Stats::new(&solve),
+    })
+}
+
+/// Formats with three significant digits and the largest unit that fits,
+/// so columns of timings stay readable.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    let (value, unit) = match nanos {
+        n if n >= 1e9 => (n / 1e9, "s"),
+        n if n >= 1e6 => (n / 1e6, "ms"),
+        n if n >= 1e3 => (n / 1e3, "µs"),
+        n => (n, "ns"),
+    };
+
+    let decimals = match value {
+        v if v >= 100.0 => 0,
+        v if v >= 10.0 => 1,
+        _ => 2,
+    };
+
+    format!("{:.*}{}", decimals, value, unit)
+}
@@ -0,0 +1,373 @@
+use crate::{
+    http::{Http, HttpError, Response},
+    submissions::Verdict,
+};
+use common::{Part, Store, StoreError};
+use serde_json::Value;
+use std::{env, error::Error, fmt, fs, io, path::PathBuf};
+
+pub const API: &str = "https://everybody.codes";
+pub const CDN: &str = "https://everybody-codes.b-cdn.net";
+
+/// Session cookie, read from this variable or from `<inputs>/session`.
+pub const SESSION_VAR: &str = "EC_SESSION";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Http(HttpError),
+    Status(String, u16),
+    Json(String, serde_json::Error),
+    MissingField(String, &'static str),
+    Store(StoreError),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session cookie, set {} or write it to <inputs>/session",
+                SESSION_VAR
+            ),
+            ClientError::Http(err) => write!(f, "request failed: {}", err),
+            ClientError::Status(url, 401 | 403) => {
+                write!(f, "session cookie rejected by `{}`", url)
+            }
+            ClientError::Status(url, status) => write!(f, "`{}` returned {}", url, status),
+            ClientError::Json(url, err) => write!(f, "invalid json from `{}`: {}", url, err),
+            ClientError::MissingField(url, field) => {
+                write!(f, "`{}` did not return `{}`", url, field)
+            }
+            ClientError::Store(err) => write!(f, "{}", err),
+            ClientError::Io(path, err) => {
+                write!(f, "failed to write `{}`: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+/// Reads the session cookie from [`SESSION_VAR`], falling back to the
+/// `session` file at the root of the store.
+pub fn session(store: &Store) -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .or_else(|| fs::read_to_string(store.root().join("session")).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn write(path: PathBuf, content: &str) -> Result<(), ClientError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| ClientError::Io(dir.to_path_buf(), e))?;
+    }
+
+    fs::write(&path, content).map_err(|e| ClientError::Io(path, e))
+}
+
+fn parse(url: &str, body: &str) -> Result<Value, ClientError> {
+    serde_json::from_str(body).map_err(|e| ClientError::Json(url.to_string(), e))
+}
+
+/// What a fetch changed in the store.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Fetched {
+    pub notes: bool,
+    /// Parts whose key is now known, including previously fetched ones.
+    pub keys: Vec<Part>,
+}
+
+/// Downloads quest notes and keys into the [`Store`], as served. Nothing
+/// already on disk is requested again.
+pub struct Client<H> {
+    http: H,
+    cookie: String,
+    api: String,
+    cdn: String,
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, session: &str) -> Self {
+        Client {
+            http,
+            cookie: format!("everybody-codes={}", session),
+            api: API.to_string(),
+            cdn: CDN.to_string(),
+        }
+    }
+
+    /// Points the client at the stand-in server.
+    #[cfg(test)]
+    pub fn with_urls(self, api: &str, cdn: &str) -> Self {
+        Client {
+            api: api.to_string(),
+            cdn: cdn.to_string(),
+            ..self
+        }
+    }
+
+    fn get(&self, url: String, authenticated: bool) -> Result<(String, Value), ClientError> {
+        let cookie = authenticated.then_some(self.cookie.as_str());
+
+        let Response { status, body } = self.http.get(&url, cookie).map_err(ClientError::Http)?;
+
+        if status != 200 {
+            return Err(ClientError::Status(url, status));
+        }
+
+        let json = parse(&url, &body)?;
+
+        Ok((body, json))
+    }
+
+    /// The seed notes are generated from, asked once and then kept in the
+    /// store.
+    pub fn seed(&self, store: &Store) -> Result<String, ClientError> {
+        let path = store.seed_path();
+
+        if let Ok(seed) = fs::read_to_string(&path) {
+            return Ok(seed.trim().to_string());
+        }
+
+        let url = format!("{}/api/user/me", self.api);
+        let (_, json) = self.get(url.clone(), true)?;
+
+        let seed = match &json["seed"] {
+            Value::Number(n) => n.to_string(),
+            Value::String(s) => s.clone(),
+            _ => return Err(ClientError::MissingField(url, "seed")),
+        };
+
+        write(path, &seed)?;
+
+        Ok(seed)
+    }
+
+    fn known_keys(&self, store: &Store, quest: u8) -> Result<Vec<Part>, ClientError> {
+        let mut keys = vec![];
+
+        for part in Part::ALL {
+            if store
+                .encrypted(quest, part)
+                .map_err(ClientError::Store)?
+                .is_some()
+            {
+                keys.push(part);
+            }
+        }
+
+        Ok(keys)
+    }
+
+    pub fn fetch(&self, store: &Store, quest: u8) -> Result<Fetched, ClientError> {
+        let mut fetched = Fetched::default();
+
+        if !store.notes_path(quest).is_file() {
+            let url = format!(
+                "{}/assets/{}/{}/input/{}.json",
+                self.cdn,
+                store.event(),
+                quest,
+                self.seed(store)?
+            );
+
+            let (body, _) = self.get(url, false)?;
+            write(store.notes_path(quest), &body)?;
+            fetched.notes = true;
+        }
+
+        fetched.keys = self.known_keys(store, quest)?;
+
+        // keys are released as parts are solved, ask again until all are known
+        if fetched.keys.len() < Part::ALL.len() {
+            let url = format!("{}/api/event/{}/quest/{}", self.api, store.event(), quest);
+
+            let (body, _) = self.get(url, true)?;
+            write(store.keys_path(quest), &body)?;
+            fetched.keys = self.known_keys(store, quest)?;
+        }
+
+        Ok(fetched)
+    }
+
+    pub fn submit(
+        &self,
+        store: &Store,
+        quest: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Verdict, ClientError> {
+        let url = format!(
+            "{}/api/event/{}/quest/{}/part/{}/answer",
+            self.api,
+            store.event(),
+            quest,
+            part.number()
+        );
+
+        let body = serde_json::json!({ "answer": answer }).to_string();
+        let Response { status, body } = self
+            .http
+            .post(&url, Some(&self.cookie), &body)
+            .map_err(ClientError::Http)?;
+
+        match status {
+            200 => {}
+            // answers sent too close to the previous one aren't checked
+            423 | 429 => return Ok(Verdict::TooSoon),
+            _ => return Err(ClientError::Status(url, status)),
+        }
+
+        match parse(&url, &body)?["correct"] {
+            Value::Bool(true) => Ok(Verdict::Correct),
+            Value::Bool(false) => Ok(Verdict::Wrong),
+            _ => Err(ClientError::MissingField(url, "correct")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{http::Ureq, standin::StandIn, temp::TempStore};
+    use common::crypto::sample::{KEY, NOTE, PLAIN};
+
+    fn temp_store() -> TempStore {
+        TempStore::new("client")
+    }
+
+    fn stand_in(keys: &str) -> StandIn {
+        let notes = format!(r#"{{"1":"{0}","2":"{0}","3":"{0}"}}"#, NOTE);
+        let server = StandIn::start();
+
+        server
+            .route("GET", "/api/user/me", 200, r#"{"id":1,"seed":42}"#)
+            .route("GET", "/assets/2025/6/input/42.json", 200, &notes)
+            .route("GET", "/api/event/2025/quest/6", 200, keys);
+
+        server
+    }
+
+    fn client(server: &StandIn) -> Client<Ureq> {
+        Client::new(Ureq::new(), "secret").with_urls(&server.url, &server.url)
+    }
+
+    #[test]
+    fn fetches_notes_and_keys() {
+        let server = stand_in(&format!(r#"{{"key1":"{}"}}"#, KEY));
+        let store = temp_store();
+
+        let fetched = client(&server).fetch(&store, 6).unwrap();
+
+        assert_eq!(
+            fetched,
+            Fetched {
+                notes: true,
+                keys: vec![Part::Part1]
+            }
+        );
+        assert_eq!(store.read(6, Part::Part1).unwrap().as_deref(), Some(PLAIN));
+        assert_eq!(store.read(6, Part::Part2).unwrap(), None);
+
+        let cookies = server
+            .requests()
+            .into_iter()
+            .map(|r| (r.path, r.cookie))
+            .collect::<Vec<_>>();
+
+        let cookie = Some("everybody-codes=secret".to_string());
+
+        assert_eq!(
+            cookies,
+            vec![
+                ("/api/user/me".to_string(), cookie.clone()),
+                ("/assets/2025/6/input/42.json".to_string(), None),
+                ("/api/event/2025/quest/6".to_string(), cookie),
+            ]
+        );
+    }
+
+    #[test]
+    fn never_fetches_twice() {
+        let keys = format!(r#"{{"key1":"{0}","key2":"{0}","key3":"{0}"}}"#, KEY);
+        let server = stand_in(&keys);
+        let store = temp_store();
+        let client = client(&server);
+
+        client.fetch(&store, 6).unwrap();
+        let fetched = client.fetch(&store, 6).unwrap();
+
+        assert!(!fetched.notes);
+        assert_eq!(fetched.keys, Part::ALL.to_vec());
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn refetches_missing_keys_only() {
+        let server = stand_in(&format!(r#"{{"key1":"{}"}}"#, KEY));
+        let store = temp_store();
+        let client = client(&server);
+
+        client.fetch(&store, 6).unwrap();
+        client.fetch(&store, 6).unwrap();
+
+        let paths = server
+            .requests()
+            .into_iter()
+            .map(|r| r.path)
+            .collect::<Vec<_>>();
+
+        assert_eq!(paths.len(), 4);
+        assert_eq!(paths[3], "/api/event/2025/quest/6");
+    }
+
+    #[test]
+    fn rejected_session() {
+        let server = StandIn::start();
+        server.route("GET", "/api/user/me", 401, "");
+
+        let err = client(&server).fetch(&temp_store(), 6).unwrap_err();
+
+        assert!(matches!(err, ClientError::Status(_, 401)));
+        assert!(err.to_string().starts_with("session cookie rejected"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let server = StandIn::start();
+        let path = "/api/event/2025/quest/6/part/2/answer";
+        server.route(
+            "POST",
+            path,
+            200,
+            r#"{"correct":false,"lengthCorrect":true}"#,
+        );
+
+        let client = client(&server);
+        let store = temp_store();
+
+        assert_eq!(
+            client.submit(&store, 6, Part::Part2, "1234").unwrap(),
+            Verdict::Wrong
+        );
+
+        server.route("POST", path, 200, r#"{"correct":true}"#);
+        assert_eq!(
+            client.submit(&store, 6, Part::Part2, "1235").unwrap(),
+            Verdict::Correct
+        );
+
+        server.route("POST", path, 429, "");
+        assert_eq!(
+            client.submit(&store, 6, Part::Part2, "1236").unwrap(),
+            Verdict::TooSoon
+        );
+
+        let request = &server.requests()[0];
+        assert_eq!(request.body, r#"{"answer":"1234"}"#);
+        assert_eq!(request.cookie.as_deref(), Some("everybody-codes=secret"));
+    }
+}
@@ -0,0 +1,57 @@
+use common::{
+    Part, Quest,
+    differential::{self, Failure},
+};
+use std::ops::Range;
+
+/// Runs the differential check of every part of `quest` that has a
+/// reference, or only `part`. Returns whether they all agreed.
+pub fn diff(quest: &dyn Quest, part: Option<Part>, seeds: Range<u64>, size: usize) -> bool {
+    let number = quest.number();
+
+    if quest.generate(seeds.start, size).is_none() {
+        eprintln!("error: quest {:02} has no generator", number);
+        return false;
+    }
+
+    let parts = Part::ALL
+        .into_iter()
+        .filter(|&p| part.is_none_or(|part| part == p))
+        .filter(|&p| quest.has_reference(p))
+        .collect::<Vec<_>>();
+
+    if parts.is_empty() {
+        eprintln!("error: quest {:02} has no reference solver", number);
+        return false;
+    }
+
+    let mut agreed = true;
+
+    for part in parts {
+        let label = format!("quest {:02} part {}", number, part.number());
+
+        let Some(mismatch) = differential::run(quest, part, seeds.clone(), size) else {
+            println!(
+                "{}: {} generated notes agree",
+                label,
+                seeds.end - seeds.start
+            );
+            continue;
+        };
+
+        match &mismatch.failure {
+            Failure::Different { answer, reference } => println!(
+                "{}: seed {} disagrees, answer {}, reference {}",
+                label, mismatch.seed, answer, reference
+            ),
+            Failure::Panic(message) => {
+                println!("{}: seed {} panicked: {}", label, mismatch.seed, message)
+            }
+        }
+
+        println!("shrunk notes:\n{}\n", mismatch.notes);
+        agreed = false;
+    }
+
+    agreed
+}
@@ -0,0 +1,83 @@
+use std::{error::Error, fmt};
+
+#[derive(Clone, Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Transport failure, HTTP error statuses are returned as [`Response`]s.
+#[derive(Debug)]
+pub struct HttpError(pub String);
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for HttpError {}
+
+/// The few requests the everybody.codes client needs, so tests can point it
+/// at a local stand-in server instead of the real site.
+pub trait Http {
+    fn get(&self, url: &str, cookie: Option<&str>) -> Result<Response, HttpError>;
+
+    /// Posts a JSON body.
+    fn post(&self, url: &str, cookie: Option<&str>, body: &str) -> Result<Response, HttpError>;
+}
+
+fn read(
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<Response, HttpError> {
+    let mut response = response.map_err(|e| HttpError(e.to_string()))?;
+
+    Ok(Response {
+        status: response.status().as_u16(),
+        body: response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| HttpError(e.to_string()))?,
+    })
+}
+
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Ureq {
+    pub fn new() -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(concat!("ec/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .into();
+
+        Ureq { agent }
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, cookie: Option<&str>) -> Result<Response, HttpError> {
+        let mut request = self.agent.get(url);
+
+        if let Some(cookie) = cookie {
+            request = request.header("Cookie", cookie);
+        }
+
+        read(request.call())
+    }
+
+    fn post(&self, url: &str, cookie: Option<&str>, body: &str) -> Result<Response, HttpError> {
+        let mut request = self
+            .agent
+            .post(url)
+            .header("Content-Type", "application/json");
+
+        if let Some(cookie) = cookie {
+            request = request.header("Cookie", cookie);
+        }
+
+        read(request.send(body))
+    }
+}
@@ -0,0 +1,74 @@
+use common::panics::{self, Panic};
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Applies `f` to every item on up to `threads` threads, each taking the
+/// next item as soon as it's done with one. Results come back in the order
+/// of the items with the time each job took, a job that panicked gives its
+/// [`Panic`] instead.
+pub fn map<I: Sync, T: Send>(
+    items: &[I],
+    threads: usize,
+    f: impl Fn(&I) -> T + Sync,
+) -> Vec<(Result<T, Panic>, Duration)> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+
+                    let start = Instant::now();
+                    let result = panics::catch(|| f(item));
+                    let elapsed = start.elapsed();
+
+                    results.lock().expect("jobs never panic holding the lock")[i] =
+                        Some((result, elapsed));
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("jobs never panic holding the lock")
+        .into_iter()
+        .map(|result| result.expect("every job ran"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order_and_survives_panics() {
+        let results = map(&[1, 2, 3, 4, 5, 6], 3, |&n| match n {
+            4 => panic!("four"),
+            n => n * 10,
+        });
+
+        let answers = results
+            .iter()
+            .map(|(r, _)| r.as_ref().map_err(|panic| panic.message.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            answers,
+            vec![Ok(&10), Ok(&20), Ok(&30), Err("four"), Ok(&50), Ok(&60)]
+        );
+        assert!(results[3].0.as_ref().unwrap_err().location.is_some());
+    }
+}
@@ -0,0 +1,711 @@
+mod client;
+mod diff;
+mod http;
+mod jobs;
+mod scaffold;
+#[cfg(test)]
+mod standin;
+mod submissions;
+mod table;
+#[cfg(test)]
+mod temp;
+mod verify;
+mod watch;
+
+use client::Client;
+use common::{
+    Answers, ArgsError, Format, Options, Part, Registry, Store, normalize,
+    output::{bench_json, error_json, panic_json, solution_json},
+    panics, print_solution, stdin,
+    timing::{Bench, Solution, format_duration},
+};
+use http::Ureq;
+use std::{
+    env, iter,
+    ops::Range,
+    path::Path,
+    process,
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+use submissions::{Log, Refusal, Submission, Verdict};
+
+const USAGE: &str = "usage:
+    ec run <quest> --part <1|2|3> [--input <path>] [--time | --bench <runs>] [--format <text|json>] [--raw]
+                     solve one part, reading --input, the stored input or stdin
+    ec watch <quest> --part <1|2|3> [--input <path>] [--raw]
+                     solve one part with --input or the stored input, then again
+                     whenever it or the sources change, comparing with the
+                     previous answer and timing
+    ec all [--part <1|2|3>] [--jobs <n>] [--time | --bench <runs>] [--format <text|json>] [--raw]
+                     solve every part that has a stored input, <n> at a time (one
+                     per core by default, always one with --bench so timings can
+                     be compared). With --time or --bench the wall clock time is
+                     printed next to the summed time of every part. Parts whose
+                     notes can't be read, fail to parse or panic don't stop the
+                     others, they're summed up at the end and make the exit
+                     status 1
+    ec list          list implemented quests and stored inputs
+    ec verify        check every stored input against inputs/<event>/answers.toml
+    ec fetch <quest> download the notes and unlocked keys of a quest, with the
+                     session cookie from EC_SESSION or inputs/session
+    ec submit <quest> <part>
+                     solve the stored input of a part and send the answer, which
+                     is logged in inputs/<event>/submissions.log. Answers already
+                     rejected aren't sent again, nor anything within a minute of
+                     the last answer to the part
+    ec gen <quest> [--size <n>] [--seed <seed>]
+                     print random notes of about <n> entries (100 by default),
+                     always the same for a given seed
+    ec diff <quest> [--part <1|2|3>] [--runs <n>] [--size <n>] [--seed <seed>]
+                     compare parts with their reference solvers on <n> generated
+                     notes (100 runs of size 20 by default), printing the first
+                     disagreement with its notes shrunk
+    ec new <quest>   create the crate of a new quest and register it
+    ec help          print this help
+
+notes are normalized before solving: a leading BOM, CRLF line endings,
+trailing whitespace and trailing blank lines are removed, unless --raw is given
+
+inputs are stored as inputs/<event>/questNN/partN.txt, set EC_INPUTS to use
+another directory and EC_EVENT to use another event than 2025
+
+set EC_MEMO_STATS to have memoized solvers print their cache hits and misses
+to stderr";
+
+fn registry() -> Registry {
+    Registry::new()
+        .register::<quest01::Quest01>()
+        .register::<quest02::Quest02>()
+        .register::<quest03::Quest03>()
+        .register::<quest04::Quest04>()
+        .register::<quest05::Quest05>()
+        .register::<quest06::Quest06>()
+        .register::<quest07::Quest07>()
+        .register::<quest08::Quest08>()
+        .register::<quest09::Quest09>()
+}
+
+enum Command {
+    Run {
+        quest: u8,
+        options: Options,
+    },
+    Watch {
+        quest: u8,
+        options: Options,
+    },
+    All {
+        options: Options,
+        jobs: usize,
+    },
+    List,
+    Verify,
+    Fetch {
+        quest: u8,
+    },
+    Submit {
+        quest: u8,
+        part: Part,
+    },
+    New {
+        quest: u8,
+    },
+    Gen {
+        quest: u8,
+        size: usize,
+        seed: u64,
+    },
+    Diff {
+        quest: u8,
+        part: Option<Part>,
+        seeds: Range<u64>,
+        size: usize,
+    },
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("error: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn abort(message: &str) -> ! {
+    eprintln!("error: {}", message);
+    process::exit(1);
+}
+
+fn help() -> ! {
+    println!("{}", USAGE);
+    process::exit(0);
+}
+
+fn parse_options(args: &[String]) -> Options {
+    match Options::parse(args.iter().cloned()) {
+        Ok(options) => options,
+        Err(ArgsError::Help) => help(),
+        Err(err) => fail(&err.to_string()),
+    }
+}
+
+fn parse_quest(quest: &str) -> u8 {
+    quest
+        .parse()
+        .unwrap_or_else(|_| fail(&format!("invalid quest `{}`", quest)))
+}
+
+// `--flag value` or `--flag=value` pairs, every flag of `gen` and `diff`
+// takes a value.
+fn flags(args: &[String]) -> Vec<(String, String)> {
+    let mut flags = vec![];
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let (flag, value) = match arg.split_once("=") {
+            Some((flag, value)) => (flag, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+
+        if !flag.starts_with("--") {
+            fail(&ArgsError::Unexpected(arg.clone()).to_string());
+        }
+
+        let value = value
+            .or_else(|| args.next().cloned())
+            .unwrap_or_else(|| fail(&ArgsError::MissingValue(flag.to_string()).to_string()));
+
+        flags.push((flag.to_string(), value));
+    }
+
+    flags
+}
+
+fn flag_value<T: FromStr>(flag: &str, value: &str) -> T {
+    value.parse().unwrap_or_else(|_| {
+        fail(&ArgsError::InvalidValue(flag.to_string(), value.to_string()).to_string())
+    })
+}
+
+// Takes `--jobs` out of the arguments of `all`, the rest are usual options.
+fn parse_all(args: &[String]) -> Command {
+    let mut jobs = None;
+    let mut rest = vec![];
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let value = match arg.split_once("=") {
+            Some(("--jobs", value)) => value.to_string(),
+            _ if arg == "--jobs" || arg == "-j" => args
+                .next()
+                .cloned()
+                .unwrap_or_else(|| fail(&ArgsError::MissingValue(arg.clone()).to_string())),
+            _ => {
+                rest.push(arg.clone());
+                continue;
+            }
+        };
+
+        match flag_value("--jobs", &value) {
+            0 => fail(&ArgsError::InvalidValue("--jobs".to_string(), value).to_string()),
+            n => jobs = Some(n),
+        }
+    }
+
+    let options = parse_options(&rest);
+
+    if options.input.is_some() {
+        fail("`all` only solves stored inputs");
+    }
+
+    // parts benched side by side compete for the cores, their timings
+    // couldn't be compared
+    let jobs = match (options.report.bench, jobs) {
+        (Some(_), Some(2..)) => fail("--bench runs one part at a time, without --jobs"),
+        (Some(_), _) => 1,
+        (None, jobs) => {
+            jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+        }
+    };
+
+    Command::All { options, jobs }
+}
+
+fn parse_gen(quest: &str, args: &[String]) -> Command {
+    let (mut size, mut seed) = (100, 0);
+
+    for (flag, value) in flags(args) {
+        match flag.as_str() {
+            "--size" => size = flag_value(&flag, &value),
+            "--seed" => seed = flag_value(&flag, &value),
+            _ => fail(&ArgsError::Unexpected(flag).to_string()),
+        }
+    }
+
+    Command::Gen {
+        quest: parse_quest(quest),
+        size,
+        seed,
+    }
+}
+
+fn parse_diff(quest: &str, args: &[String]) -> Command {
+    let (mut part, mut runs, mut size, mut seed) = (None, 100, 20, 0u64);
+
+    for (flag, value) in flags(args) {
+        match flag.as_str() {
+            "--part" => part = Some(flag_value(&flag, &value)),
+            "--runs" => runs = flag_value(&flag, &value),
+            "--size" => size = flag_value(&flag, &value),
+            "--seed" => seed = flag_value(&flag, &value),
+            _ => fail(&ArgsError::Unexpected(flag).to_string()),
+        }
+    }
+
+    let end = seed
+        .checked_add(runs)
+        .unwrap_or_else(|| fail(&format!("--seed {} leaves no room for {} runs", seed, runs)));
+
+    Command::Diff {
+        quest: parse_quest(quest),
+        part,
+        seeds: seed..end,
+        size,
+    }
+}
+
+fn parse_command(args: &[String]) -> Command {
+    match args.split_first() {
+        Some((command, rest)) if command == "all" => parse_all(rest),
+        Some((command, [])) if command == "list" => Command::List,
+        Some((command, [])) if command == "verify" => Command::Verify,
+        Some((command, _)) if command == "help" || command == "--help" || command == "-h" => help(),
+        Some((command, [quest, rest @ ..])) if command == "gen" => parse_gen(quest, rest),
+        Some((command, [quest, rest @ ..])) if command == "diff" => parse_diff(quest, rest),
+        Some((command, [quest])) if command == "new" => Command::New {
+            quest: parse_quest(quest),
+        },
+        Some((command, [quest])) if command == "fetch" => Command::Fetch {
+            quest: parse_quest(quest),
+        },
+        Some((command, [quest, part])) if command == "submit" => Command::Submit {
+            quest: parse_quest(quest),
+            part: part
+                .parse()
+                .unwrap_or_else(|e: ArgsError| fail(&e.to_string())),
+        },
+        Some((command, [quest, rest @ ..])) if command == "run" || command == "watch" => {
+            let quest = parse_quest(quest);
+
+            let options = parse_options(rest);
+
+            if options.part.is_none() {
+                fail(&ArgsError::MissingPart.to_string());
+            }
+
+            match command.as_str() {
+                "run" => Command::Run { quest, options },
+                _ => Command::Watch { quest, options },
+            }
+        }
+        None => fail("a command is required"),
+        _ => fail("invalid arguments"),
+    }
+}
+
+// The workspace this binary was built from.
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("ec is a workspace member")
+}
+
+fn read_input(store: &Store, quest: u8, part: Part) -> Option<String> {
+    store
+        .read(quest, part)
+        .unwrap_or_else(|err| abort(&err.to_string()))
+}
+
+enum Timings {
+    Single(Solution),
+    Bench(Bench),
+}
+
+// Solves every stored input on `jobs` threads, printing a plain list of
+// answers, a table of timings or JSON records in quest and part order. Parts
+// whose notes can't be read, fail to parse or panic are listed as failed and
+// summed up at the end.
+// Returns whether every part could be solved.
+fn solve_all(registry: &Registry, store: &Store, options: &Options, jobs: usize) -> bool {
+    let report = &options.report;
+    let mut failures = vec![];
+    let mut rows = vec![];
+
+    let inputs = registry
+        .iter()
+        .flat_map(|quest| Part::ALL.map(|part| (quest, part)))
+        .filter(|(_, part)| options.part.is_none_or(|p| p == *part))
+        .filter_map(|(quest, part)| {
+            // unreadable notes fail their part like a parse error would
+            let input = store.read(quest.number(), part).transpose()?;
+            Some((quest, part, input.map(|input| options.prepare(input))))
+        })
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+
+    let results = jobs::map(&inputs, jobs, |(quest, part, input)| {
+        let input = input.as_ref().map_err(|err| err.to_string())?;
+
+        match report.bench {
+            Some(runs) => quest.bench(input, *part, runs).map(Timings::Bench),
+            None => quest.run(input, *part).map(Timings::Single),
+        }
+        .map_err(|err| err.to_string())
+    });
+
+    let wall = start.elapsed();
+    let mut summed = Duration::ZERO;
+
+    for ((quest, part, _), (result, elapsed)) in inputs.iter().zip(results) {
+        let (number, part) = (quest.number(), *part);
+        let label = format!("{:02}", number);
+
+        // failed parts took their time too
+        summed += elapsed;
+
+        // failures keep their message and JSON record
+        let timings = match result {
+            Ok(timings) => timings.map_err(|err| (error_json(number, part, &err), err)),
+            Err(panic) => Err((panic_json(number, part, &panic), panic.to_string())),
+        };
+
+        match (timings, report.format) {
+            (Ok(Timings::Single(s)), Format::Json) => {
+                println!("{}", solution_json(number, part, &s))
+            }
+            (Ok(Timings::Bench(b)), Format::Json) => {
+                println!("{}", bench_json(number, part, &b))
+            }
+            (Ok(Timings::Single(s)), Format::Text) if report.time => rows.push(vec![
+                label,
+                part.number().to_string(),
+                s.answer,
+                format_duration(s.parse),
+                format_duration(s.solve),
+            ]),
+            (Ok(Timings::Single(s)), Format::Text) => {
+                println!("quest {} part {}: {}", label, part.number(), s.answer)
+            }
+            (Ok(Timings::Bench(b)), Format::Text) => rows.push(vec![
+                label,
+                part.number().to_string(),
+                b.answer,
+                format_duration(b.parse.median),
+                format_duration(b.solve.min),
+                format_duration(b.solve.median),
+                format_duration(b.solve.mean),
+                format_duration(b.solve.max),
+            ]),
+            (Err((json, err)), Format::Json) => {
+                println!("{}", json);
+                failures.push((number, part, err));
+            }
+            (Err((_, err)), Format::Text) => {
+                // the timing columns stay empty
+                let empty = match (report.bench, report.time) {
+                    (Some(_), _) => Some(5),
+                    (None, true) => Some(2),
+                    (None, false) => None,
+                };
+
+                match empty {
+                    Some(n) => rows.push(
+                        [label, part.number().to_string(), "failed".to_string()]
+                            .into_iter()
+                            .chain(iter::repeat_n("-".to_string(), n))
+                            .collect(),
+                    ),
+                    None => println!("quest {} part {}: failed", label, part.number()),
+                }
+
+                failures.push((number, part, err));
+            }
+        }
+    }
+
+    match (report.bench, report.format) {
+        (Some(_), Format::Text) => table::print(
+            &[
+                "quest", "part", "answer", "parse", "min", "median", "mean", "max",
+            ],
+            &rows,
+        ),
+        (None, Format::Text) if report.time => {
+            table::print(&["quest", "part", "answer", "parse", "solve"], &rows)
+        }
+        _ => {}
+    }
+
+    if report.format == Format::Text && (report.time || report.bench.is_some()) {
+        println!(
+            "\n{} wall clock, {} summed over {} parts, {} at a time",
+            format_duration(wall),
+            format_duration(summed),
+            inputs.len(),
+            jobs.min(inputs.len()).max(1)
+        );
+    }
+
+    if !failures.is_empty() && report.format == Format::Text {
+        eprintln!(
+            "\nerror: {} of {} parts failed",
+            failures.len(),
+            inputs.len()
+        );
+
+        for (number, part, err) in &failures {
+            eprintln!("quest {:02} part {}: {}", number, part.number(), err);
+        }
+    }
+
+    failures.is_empty()
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let registry = registry();
+    let store = Store::from_env();
+
+    match parse_command(&args) {
+        Command::Run { quest, options } => {
+            let quest = registry
+                .get(quest)
+                .unwrap_or_else(|| fail(&format!("quest {} is not implemented", quest)));
+
+            let part = options.part.expect("part is checked when parsing");
+
+            let input = match &options.input {
+                Some(_) => options
+                    .read_input()
+                    .unwrap_or_else(|e| abort(&e.to_string())),
+                None => {
+                    options.prepare(read_input(&store, quest.number(), part).unwrap_or_else(stdin))
+                }
+            };
+
+            match panics::catch(|| print_solution(quest, &input, part, &options.report)) {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => abort(&err.to_string()),
+                Err(panic) => abort(&panic.to_string()),
+            }
+        }
+        Command::Watch { quest, options } => {
+            if registry.get(quest).is_none() {
+                fail(&format!("quest {} is not implemented", quest));
+            }
+
+            watch::watch(workspace(), &store, quest, options).unwrap_or_else(|e| abort(&e));
+        }
+        Command::All { options, jobs } => {
+            if !solve_all(&registry, &store, &options, jobs) {
+                process::exit(1);
+            }
+        }
+        Command::List => {
+            let mut errors = vec![];
+
+            for quest in registry.iter() {
+                let parts = Part::ALL
+                    .iter()
+                    .map(|&part| match store.contains(quest.number(), part) {
+                        Ok(true) => format!("{}", part.number()),
+                        Ok(false) => format!("{} (no input)", part.number()),
+                        Err(err) => {
+                            errors.push(err.to_string());
+                            format!("{} (unreadable)", part.number())
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                println!("quest {:02}: parts {}", quest.number(), parts);
+            }
+
+            // the parts of a quest share their notes file
+            errors.dedup();
+
+            for err in errors {
+                eprintln!("error: {}", err);
+            }
+        }
+        Command::Verify => {
+            let answers =
+                Answers::load(&store.answers_path()).unwrap_or_else(|e| abort(&e.to_string()));
+
+            if verify::verify(&registry, &store, &answers).failed > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Fetch { quest } => {
+            let session = client::session(&store)
+                .unwrap_or_else(|| abort(&client::ClientError::MissingSession.to_string()));
+
+            let fetched = Client::new(Ureq::new(), &session)
+                .fetch(&store, quest)
+                .unwrap_or_else(|e| abort(&e.to_string()));
+
+            let keys = fetched
+                .keys
+                .iter()
+                .map(|p| p.number().to_string())
+                .collect::<Vec<_>>();
+
+            println!(
+                "quest {:02}: notes {}, keys for parts [{}]",
+                quest,
+                if fetched.notes {
+                    "downloaded"
+                } else {
+                    "already stored"
+                },
+                keys.join(", ")
+            );
+        }
+        Command::Submit { quest, part } => {
+            let quest = registry
+                .get(quest)
+                .unwrap_or_else(|| fail(&format!("quest {} is not implemented", quest)));
+
+            let number = quest.number();
+            let input = read_input(&store, number, part).unwrap_or_else(|| {
+                abort(&format!(
+                    "no stored input for quest {:02} part {}",
+                    number,
+                    part.number()
+                ))
+            });
+
+            let answer = quest
+                .solve(&normalize(&input), part)
+                .unwrap_or_else(|e| abort(&e.to_string()));
+
+            let mut log = Log::load(&store.submissions_path()).unwrap_or_else(|e| {
+                abort(&format!(
+                    "failed to read `{}`: {}",
+                    store.submissions_path().display(),
+                    e
+                ))
+            });
+
+            match log.refusal(number, part, &answer, submissions::now()) {
+                Some(Refusal::Solved(solved)) => {
+                    println!(
+                        "quest {:02} part {}: already solved with `{}`",
+                        number,
+                        part.number(),
+                        solved
+                    );
+                    return;
+                }
+                Some(Refusal::Rejected) => abort(&format!(
+                    "`{}` was already rejected for quest {:02} part {}",
+                    answer,
+                    number,
+                    part.number()
+                )),
+                Some(Refusal::Cooldown(wait)) => abort(&format!(
+                    "quest {:02} part {} was answered too recently, try again in {}s",
+                    number,
+                    part.number(),
+                    wait
+                )),
+                None => {}
+            }
+
+            let session = client::session(&store)
+                .unwrap_or_else(|| abort(&client::ClientError::MissingSession.to_string()));
+
+            let verdict = Client::new(Ureq::new(), &session)
+                .submit(&store, number, part, &answer)
+                .unwrap_or_else(|e| abort(&e.to_string()));
+
+            if let Err(err) = log.append(Submission::new(number, part, verdict, &answer)) {
+                eprintln!(
+                    "warning: failed to write `{}`: {}",
+                    log.path().display(),
+                    err
+                );
+            }
+
+            let message = match verdict {
+                Verdict::Correct => "correct",
+                Verdict::Wrong => "wrong",
+                Verdict::TooSoon => "not checked, submitted too soon after the previous answer",
+            };
+
+            println!(
+                "quest {:02} part {}: `{}` is {}",
+                number,
+                part.number(),
+                answer,
+                message
+            );
+
+            if verdict != Verdict::Correct {
+                process::exit(1);
+            }
+        }
+        Command::New { quest } => {
+            scaffold::create(workspace(), quest).unwrap_or_else(|e| abort(&e));
+            println!("created quest{:02}, rebuild ec to run it", quest);
+        }
+        Command::Gen { quest, size, seed } => {
+            let quest = registry
+                .get(quest)
+                .unwrap_or_else(|| fail(&format!("quest {} is not implemented", quest)));
+
+            match quest.generate(seed, size) {
+                Some(notes) => println!("{}", notes),
+                None => abort(&format!("quest {:02} has no generator", quest.number())),
+            }
+        }
+        Command::Diff {
+            quest,
+            part,
+            seeds,
+            size,
+        } => {
+            let quest = registry
+                .get(quest)
+                .unwrap_or_else(|| fail(&format!("quest {} is not implemented", quest)));
+
+            if !diff::diff(quest, part, seeds, size) {
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_notes_are_solvable() {
+        for quest in registry().iter() {
+            for seed in 0..3 {
+                let Some(notes) = quest.generate(seed, 20) else {
+                    continue;
+                };
+
+                assert_eq!(quest.generate(seed, 20).as_ref(), Some(&notes));
+
+                for part in Part::ALL {
+                    if let Err(err) = quest.solve(&notes, part) {
+                        panic!("seed {}: {}", seed, err);
+                    }
+                }
+            }
+        }
+    }
+}
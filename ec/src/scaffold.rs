@@ -0,0 +1,220 @@
+use std::{fs, path::Path};
+
+const MANIFEST: &str = r#"[package]
+name = "questNN"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+common = { path = "../common" }
+"#;
+
+const MAIN: &str = r#"use questNN::QuestNN;
+
+fn main() {
+    common::run::<QuestNN>();
+}
+"#;
+
+const LIB: &str = r#"use common::{ParseError, Solver, parse};
+use std::fmt::Display;
+
+pub struct QuestNN;
+
+impl Solver for QuestNN {
+    const QUEST: u8 = N;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(parse::lines(input).map(String::from).collect())
+    }
+
+    fn part_1(_lines: &Vec<String>) -> impl Display {
+        todo!()
+    }
+
+    fn part_2(_lines: &Vec<String>) -> impl Display {
+        todo!()
+    }
+
+    fn part_3(_lines: &Vec<String>) -> impl Display {
+        todo!()
+    }
+}
+
+// Parts without a fixture fail, enable once fixtures/ has the examples.
+// common::fixture_tests!(QuestNN);
+"#;
+
+fn fill(template: &str, quest: u8) -> String {
+    template
+        .replace("NN", &format!("{:02}", quest))
+        .replace("= N;", &format!("= {};", quest))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("failed to read `{}`: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("failed to create `{}`: {}", dir.display(), e))?;
+    }
+
+    fs::write(path, content).map_err(|e| format!("failed to write `{}`: {}", path.display(), e))
+}
+
+// Adds a crate to the one line `members = [...]` list, keeping it sorted.
+fn add_member(manifest: &str, member: &str) -> Option<String> {
+    let start = manifest.find("members = [")? + "members = [".len();
+    let end = start + manifest[start..].find(']')?;
+
+    let mut members = manifest[start..end]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .chain([member])
+        .collect::<Vec<_>>();
+
+    members.sort();
+    members.dedup();
+
+    let members = members
+        .iter()
+        .map(|m| format!("\"{}\"", m))
+        .collect::<Vec<_>>();
+
+    Some(format!(
+        "{}{}{}",
+        &manifest[..start],
+        members.join(", "),
+        &manifest[end..]
+    ))
+}
+
+// Inserts `line` after the last line containing `marker`, with the same
+// indentation.
+fn insert_after_last(content: &str, marker: &str, line: &str) -> Option<String> {
+    let lines = content.lines().collect::<Vec<_>>();
+    let index = lines.iter().rposition(|l| l.contains(marker))?;
+    let indent = &lines[index][..lines[index].len() - lines[index].trim_start().len()];
+
+    let mut updated = lines[..=index].to_vec();
+    let inserted = format!("{}{}", indent, line);
+    updated.push(&inserted);
+    updated.extend(&lines[index + 1..]);
+
+    Some(updated.join("\n") + "\n")
+}
+
+fn update(path: &Path, edit: impl FnOnce(&str) -> Option<String>) -> Result<(), String> {
+    let content = read(path)?;
+    let updated = edit(&content).ok_or_else(|| {
+        format!(
+            "don't know where to register the quest in `{}`",
+            path.display()
+        )
+    })?;
+
+    write(path, &updated)
+}
+
+/// Creates the `questNN` crate in the workspace at `root`, adds it to the
+/// workspace members and registers it in the `ec` runner.
+pub fn create(root: &Path, quest: u8) -> Result<(), String> {
+    let name = format!("quest{:02}", quest);
+    let dir = root.join(&name);
+
+    if quest == 0 {
+        return Err("quests are numbered from 1".to_string());
+    }
+
+    if dir.exists() {
+        return Err(format!("`{}` already exists", dir.display()));
+    }
+
+    write(&dir.join("Cargo.toml"), &fill(MANIFEST, quest))?;
+    write(&dir.join("src/main.rs"), &fill(MAIN, quest))?;
+    write(&dir.join("src/lib.rs"), &fill(LIB, quest))?;
+    // git doesn't keep empty directories
+    write(&dir.join("fixtures/.gitkeep"), "")?;
+
+    update(&root.join("Cargo.toml"), |c| add_member(c, &name))?;
+    update(&root.join("ec/Cargo.toml"), |c| {
+        insert_after_last(
+            c,
+            "{ path = \"../quest",
+            &format!("{0} = {{ path = \"../{0}\" }}", name),
+        )
+    })?;
+    update(&root.join("ec/src/main.rs"), |c| {
+        insert_after_last(
+            c,
+            ".register::<quest",
+            &format!(".register::<{}::{}>()", name, fill("QuestNN", quest)),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn adds_members_in_order() {
+        let manifest = "[workspace]\nmembers = [\"common\", \"quest01\", \"quest03\"]\n";
+
+        assert_eq!(
+            add_member(manifest, "quest02").unwrap(),
+            "[workspace]\nmembers = [\"common\", \"quest01\", \"quest02\", \"quest03\"]\n"
+        );
+        assert_eq!(add_member("[workspace]\n", "quest02"), None);
+    }
+
+    #[test]
+    fn creates_quest() {
+        let root = env::temp_dir().join(format!("ec-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        write(&root.join("Cargo.toml"), "members = [\"common\", \"ec\"]\n").unwrap();
+        write(
+            &root.join("ec/Cargo.toml"),
+            "[dependencies]\nquest01 = { path = \"../quest01\" }\n",
+        )
+        .unwrap();
+        write(
+            &root.join("ec/src/main.rs"),
+            "    Registry::new()\n        .register::<quest01::Quest01>()\n}\n",
+        )
+        .unwrap();
+
+        create(&root, 12).unwrap();
+
+        assert!(
+            read(&root.join("quest12/src/lib.rs"))
+                .unwrap()
+                .contains("const QUEST: u8 = 12;")
+        );
+        assert!(root.join("quest12/fixtures").is_dir());
+        assert!(
+            read(&root.join("quest12/src/lib.rs"))
+                .unwrap()
+                .contains("// common::fixture_tests!(Quest12);")
+        );
+        assert_eq!(
+            read(&root.join("Cargo.toml")).unwrap(),
+            "members = [\"common\", \"ec\", \"quest12\"]\n"
+        );
+        assert!(
+            read(&root.join("ec/src/main.rs"))
+                .unwrap()
+                .contains("\n        .register::<quest12::Quest12>()\n}")
+        );
+        assert!(create(&root, 12).unwrap_err().ends_with("already exists"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,116 @@
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// A request received by the stand-in server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+type Routes = HashMap<(String, String), (u16, String)>;
+
+/// Local stand-in for everybody.codes serving canned responses, so the
+/// client can be tested offline. Unknown routes answer 404.
+pub struct StandIn {
+    pub url: String,
+    routes: Arc<Mutex<Routes>>,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+
+    let mut words = line.split_whitespace();
+    let (method, path) = (words.next()?.to_string(), words.next()?.to_string());
+    let (mut cookie, mut length) = (None, 0);
+
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).ok()?;
+
+        let Some((name, value)) = header.trim_end().split_once(": ") else {
+            break;
+        };
+
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = Some(value.to_string()),
+            "content-length" => length = value.parse().ok()?,
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(Request {
+        method,
+        path,
+        cookie,
+        body: String::from_utf8(body).ok()?,
+    })
+}
+
+impl StandIn {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind stand-in");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let routes = Arc::new(Mutex::new(Routes::new()));
+        let requests = Arc::new(Mutex::new(vec![]));
+        let (shared_routes, shared_requests) = (routes.clone(), requests.clone());
+
+        // the thread lives as long as the test binary, which is fine for tests
+        thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let Some(request) = read_request(&stream) else {
+                    continue;
+                };
+
+                let (status, body) = shared_routes
+                    .lock()
+                    .unwrap()
+                    .get(&(request.method.clone(), request.path.clone()))
+                    .cloned()
+                    .unwrap_or((404, String::new()));
+
+                shared_requests.lock().unwrap().push(request);
+
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+
+        StandIn {
+            url,
+            routes,
+            requests,
+        }
+    }
+
+    pub fn route(&self, method: &str, path: &str, status: u16, body: &str) -> &Self {
+        self.routes.lock().unwrap().insert(
+            (method.to_string(), path.to_string()),
+            (status, body.to_string()),
+        );
+        self
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
@@ -0,0 +1,237 @@
+use common::Part;
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// How long everybody.codes makes you wait after an answer before it checks
+/// another one for the same part, in seconds.
+pub const COOLDOWN: u64 = 60;
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// How everybody.codes answered a submission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    /// Rate limited, the answer was not checked and can be sent again later.
+    TooSoon,
+}
+
+impl Verdict {
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooSoon => "too-soon",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "correct" => Some(Verdict::Correct),
+            "wrong" => Some(Verdict::Wrong),
+            "too-soon" => Some(Verdict::TooSoon),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the unix epoch.
+    pub time: u64,
+    pub quest: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl Submission {
+    pub fn new(quest: u8, part: Part, verdict: Verdict, answer: &str) -> Self {
+        Submission {
+            time: now(),
+            quest,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        }
+    }
+
+    // `<time>\t<quest>\t<part>\t<verdict>\t<answer>`
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(5, '\t');
+
+        Some(Submission {
+            time: fields.next()?.parse().ok()?,
+            quest: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            verdict: Verdict::from_name(fields.next()?)?,
+            answer: fields.next()?.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.time,
+            self.quest,
+            self.part.number(),
+            self.verdict,
+            self.answer
+        )
+    }
+}
+
+/// Why an answer is not sent again.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    Solved(String),
+    Rejected,
+    /// The last answer was sent too recently, with the seconds left to wait.
+    Cooldown(u64),
+}
+
+/// Append only log of the submissions, one tab separated line each.
+pub struct Log {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl Log {
+    /// Loads the log at `path`, a missing file is an empty log. Lines that
+    /// can't be read are skipped.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(Log {
+            path: path.to_path_buf(),
+            submissions: content.lines().filter_map(Submission::parse).collect(),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether sending `answer` at `now` would be pointless: the part is
+    /// already solved, this exact answer was already found wrong, or the
+    /// server would turn it away because the last one was inside the
+    /// [`COOLDOWN`].
+    pub fn refusal(&self, quest: u8, part: Part, answer: &str, now: u64) -> Option<Refusal> {
+        let submissions = self
+            .submissions
+            .iter()
+            .filter(|s| s.quest == quest && s.part == part);
+
+        if let Some(solved) = submissions.clone().find(|s| s.verdict == Verdict::Correct) {
+            return Some(Refusal::Solved(solved.answer.clone()));
+        }
+
+        if submissions
+            .clone()
+            .any(|s| s.verdict == Verdict::Wrong && s.answer == answer)
+        {
+            return Some(Refusal::Rejected);
+        }
+
+        // answers turned away as too soon count as well, the server saw them
+        let ready = submissions.map(|s| s.time + COOLDOWN).max()?;
+        (ready > now).then(|| Refusal::Cooldown(ready - now))
+    }
+
+    pub fn append(&mut self, submission: Submission) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        writeln!(file, "{}", submission)?;
+        self.submissions.push(submission);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(lines: &[&str]) -> Log {
+        Log {
+            path: PathBuf::new(),
+            submissions: lines.iter().filter_map(|l| Submission::parse(l)).collect(),
+        }
+    }
+
+    #[test]
+    fn parses_lines() {
+        let submission = Submission::parse("1760000000\t6\t2\twrong\t1234").unwrap();
+
+        assert_eq!(submission.part, Part::Part2);
+        assert_eq!(submission.verdict, Verdict::Wrong);
+        assert_eq!(submission.to_string(), "1760000000\t6\t2\twrong\t1234");
+        assert_eq!(Submission::parse("1760000000\t6\t2\tmaybe\t1234"), None);
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let log = log(&[
+            "1\t6\t1\ttoo-soon\t12",
+            "2\t6\t1\twrong\t12",
+            "3\t6\t2\twrong\t7",
+            "4\t6\t2\tcorrect\t8",
+        ]);
+
+        let later = 2 + COOLDOWN;
+
+        assert_eq!(
+            log.refusal(6, Part::Part1, "12", later),
+            Some(Refusal::Rejected)
+        );
+        assert_eq!(log.refusal(6, Part::Part1, "13", later), None);
+        assert_eq!(
+            log.refusal(6, Part::Part2, "9", later),
+            Some(Refusal::Solved("8".to_string()))
+        );
+        assert_eq!(log.refusal(7, Part::Part1, "12", later), None);
+    }
+
+    #[test]
+    fn refuses_during_cooldown() {
+        let log = log(&["100\t6\t1\twrong\t12", "130\t6\t1\ttoo-soon\t13"]);
+
+        assert_eq!(
+            log.refusal(6, Part::Part1, "14", 150),
+            Some(Refusal::Cooldown(COOLDOWN - 20))
+        );
+        assert_eq!(log.refusal(6, Part::Part1, "14", 130 + COOLDOWN), None);
+        assert_eq!(log.refusal(6, Part::Part2, "14", 150), None);
+    }
+}
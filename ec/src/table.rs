@@ -0,0 +1,27 @@
+/// Prints rows under their headers, every column right aligned.
+pub fn print(headers: &[&str], rows: &[Vec<String>]) {
+    let widths = headers
+        .iter()
+        .enumerate()
+        .map(|(i, header)| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .fold(header.chars().count(), usize::max)
+        })
+        .collect::<Vec<_>>();
+
+    let line = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+
+    println!("{}", line(headers.to_vec()));
+
+    for row in rows {
+        println!("{}", line(row.iter().map(|c| c.as_str()).collect()));
+    }
+}
@@ -0,0 +1,40 @@
+use common::Store;
+use std::{
+    env, fs,
+    ops::Deref,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A store in a fresh temporary directory, removed again when dropped.
+pub struct TempStore(Store);
+
+impl TempStore {
+    /// `name` tells the directories of different tests apart.
+    pub fn new(name: &str) -> Self {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        let root = env::temp_dir().join(format!(
+            "ec-{}-{}-{}",
+            name,
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::SeqCst)
+        ));
+
+        let _ = fs::remove_dir_all(&root);
+        TempStore(Store::new(root, "2025"))
+    }
+}
+
+impl Deref for TempStore {
+    type Target = Store;
+
+    fn deref(&self) -> &Store {
+        &self.0
+    }
+}
+
+impl Drop for TempStore {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(self.0.root());
+    }
+}
@@ -0,0 +1,190 @@
+use common::{Answers, Part, Quest, Registry, Store, normalize, panics};
+
+enum Status {
+    Passed,
+    Failed { expected: String, actual: String },
+    Missing { actual: String },
+    Error(String),
+}
+
+fn check(quest: &dyn Quest, part: Part, input: &str, answers: &Answers) -> Status {
+    let actual = match panics::catch(|| quest.solve(input, part)) {
+        Ok(Ok(actual)) => actual,
+        Ok(Err(err)) => return Status::Error(err.to_string()),
+        Err(panic) => return Status::Error(panic.to_string()),
+    };
+
+    match answers.get(quest.number(), part) {
+        Some(expected) if expected == actual => Status::Passed,
+        Some(expected) => Status::Failed {
+            expected: expected.to_string(),
+            actual,
+        },
+        None => Status::Missing { actual },
+    }
+}
+
+// Prints both answers on top of each other with a caret under the first
+// character that differs.
+fn print_diff(expected: &str, actual: &str) {
+    let same = expected
+        .chars()
+        .zip(actual.chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    println!("    - {}", expected);
+    println!("    + {}", actual);
+    println!("      {}^", " ".repeat(same));
+}
+
+/// Counts of a verification run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    /// Solved, but there's no accepted answer to compare with.
+    pub missing_answer: usize,
+    /// Accepted answers without a stored input to solve.
+    pub missing_input: usize,
+}
+
+/// Solves every stored input and compares it with the accepted answers.
+/// Notes that can't be read fail their part. Everything that has an answer
+/// matched when nothing `failed`.
+pub fn verify(registry: &Registry, store: &Store, answers: &Answers) -> Summary {
+    let mut summary = Summary::default();
+
+    for quest in registry.iter() {
+        for part in Part::ALL {
+            let label = format!("quest {:02} part {}", quest.number(), part.number());
+
+            // unreadable notes fail their part, the others are still checked
+            let status = match store.read(quest.number(), part) {
+                Ok(Some(input)) => check(quest, part, &normalize(&input), answers),
+                Ok(None) => {
+                    if answers.get(quest.number(), part).is_some() {
+                        summary.missing_input += 1;
+                        println!("{}: missing input", label);
+                    }
+                    continue;
+                }
+                Err(err) => Status::Error(err.to_string()),
+            };
+
+            match status {
+                Status::Passed => {
+                    summary.passed += 1;
+                    println!("{}: ok", label);
+                }
+                Status::Failed { expected, actual } => {
+                    summary.failed += 1;
+                    println!("{}: FAILED", label);
+                    print_diff(&expected, &actual);
+                }
+                Status::Missing { actual } => {
+                    summary.missing_answer += 1;
+                    println!("{}: missing answer, got {}", label, actual);
+                }
+                Status::Error(err) => {
+                    summary.failed += 1;
+                    println!("{}: FAILED", label);
+                    println!("{}", err);
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing answer, {} missing input",
+        summary.passed, summary.failed, summary.missing_answer, summary.missing_input
+    );
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::TempStore;
+    use common::{ParseError, Solver};
+    use std::{fmt::Display, fs};
+
+    struct Lengths;
+
+    impl Solver for Lengths {
+        const QUEST: u8 = 1;
+
+        type Input = String;
+
+        fn parse(input: &str) -> Result<String, ParseError> {
+            Ok(input.to_string())
+        }
+
+        fn part_1(input: &String) -> impl Display {
+            input.len()
+        }
+
+        fn part_2(input: &String) -> impl Display {
+            input.len() * 2
+        }
+
+        fn part_3(input: &String) -> impl Display {
+            input.len() * 3
+        }
+    }
+
+    // verifies quest 1 with `input` stored for part 1 and `answer` accepted
+    // for the given part
+    fn run(input: &str, part: Part, answer: &str) -> Summary {
+        let store = TempStore::new("verify");
+        store.write(1, Part::Part1, input).unwrap();
+
+        let mut answers = Answers::default();
+        answers.insert(1, part, answer);
+
+        let registry = Registry::new().register::<Lengths>();
+        verify(&registry, &store, &answers)
+    }
+
+    #[test]
+    fn matching_answer() {
+        let summary = run("abc", Part::Part1, "3");
+        assert_eq!((summary.passed, summary.failed), (1, 0));
+    }
+
+    #[test]
+    fn mismatching_answer() {
+        let summary = run("abc", Part::Part1, "4");
+        assert_eq!((summary.passed, summary.failed), (0, 1));
+    }
+
+    #[test]
+    fn missing_input_and_answer() {
+        assert_eq!(
+            run("abc", Part::Part3, "9"),
+            Summary {
+                passed: 0,
+                failed: 0,
+                missing_answer: 1,
+                missing_input: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn unreadable_notes_fail_their_part_only() {
+        let store = TempStore::new("verify");
+        store.write(1, Part::Part1, "abc").unwrap();
+        fs::write(store.notes_path(1), "{").unwrap();
+
+        let mut answers = Answers::default();
+        answers.insert(1, Part::Part1, "3");
+        answers.insert(1, Part::Part2, "6");
+
+        let registry = Registry::new().register::<Lengths>();
+        let summary = verify(&registry, &store, &answers);
+
+        assert_eq!((summary.passed, summary.failed), (1, 2));
+    }
+}
@@ -0,0 +1,251 @@
+use common::{Options, Part, Store, timing::format_duration};
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde_json::Value;
+use std::{
+    fs,
+    io::Write,
+    path::{self, Path, PathBuf},
+    process::{Command, Stdio},
+    sync::mpsc,
+    time::Duration,
+};
+
+// Editors write a file in several steps, changes this close together are
+// handled as one.
+const SETTLE: Duration = Duration::from_millis(150);
+
+/// The answer and solving time of one run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    pub answer: String,
+    pub solve: Duration,
+}
+
+/// One line describing `current`, compared with the previous run.
+pub fn describe(previous: Option<&Outcome>, current: &Outcome) -> String {
+    let time = format_duration(current.solve);
+
+    match previous {
+        None => format!("{} in {}", current.answer, time),
+        Some(previous) => {
+            let answer = match previous.answer == current.answer {
+                true => format!("{} (unchanged)", current.answer),
+                false => format!("{} (was {})", current.answer, previous.answer),
+            };
+
+            format!(
+                "{} in {} (was {})",
+                answer,
+                time,
+                format_duration(previous.solve)
+            )
+        }
+    }
+}
+
+struct Watched {
+    root: PathBuf,
+    quest: u8,
+    part: Part,
+    options: Options,
+    /// The `--input` file, or the stored notes of the quest.
+    input: PathBuf,
+    sources: Vec<PathBuf>,
+}
+
+impl Watched {
+    fn package(&self) -> String {
+        format!("quest{:02}", self.quest)
+    }
+
+    fn relevant(&self, path: &Path) -> bool {
+        let source = self.sources.iter().any(|dir| path.starts_with(dir))
+            && path.extension().is_some_and(|ext| ext == "rs");
+
+        // stored notes are spread over a few files of the quest's directory
+        let input = path == self.input || path.parent() == Some(self.input.as_path());
+
+        source || input
+    }
+
+    fn read_input(&self, store: &Store) -> Result<String, String> {
+        let input = match &self.options.input {
+            Some(_) => self.options.read_input().map_err(|e| e.to_string())?,
+            None => store
+                .read(self.quest, self.part)
+                .map_err(|e| e.to_string())?
+                .map(|input| self.options.prepare(input))
+                .ok_or_else(|| {
+                    format!(
+                        "no stored input for quest {:02} part {}",
+                        self.quest,
+                        self.part.number()
+                    )
+                })?,
+        };
+
+        Ok(input)
+    }
+
+    // Builds and runs the quest's own binary so source changes are picked
+    // up, compiler errors and panics go straight to stderr. The input is
+    // already prepared, so the child takes it as is.
+    fn run(&self, store: &Store) -> Result<Outcome, String> {
+        let input = self.read_input(store)?;
+
+        let mut child = Command::new(env!("CARGO"))
+            .current_dir(&self.root)
+            .args(["run", "--release", "--quiet", "-p", &self.package(), "--"])
+            .args([
+                "--part",
+                &self.part.number().to_string(),
+                "--format",
+                "json",
+                "--raw",
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("failed to run cargo: {}", e))?;
+
+        // the child may exit before reading everything, e.g. when it fails to
+        // build, that's reported through its status instead
+        if let Some(mut stdin) = child.stdin.take() {
+            let _ = stdin.write_all(input.as_bytes());
+        }
+
+        let output = child
+            .wait_with_output()
+            .map_err(|e| format!("failed to run cargo: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!("{} failed", self.package()));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let record: Value = serde_json::from_str(stdout.trim())
+            .map_err(|_| format!("unexpected output from {}: {}", self.package(), stdout))?;
+
+        match (record["answer"].as_str(), record["solve_ms"].as_f64()) {
+            (Some(answer), Some(ms)) => Ok(Outcome {
+                answer: answer.to_string(),
+                solve: Duration::from_secs_f64(ms / 1e3),
+            }),
+            _ => Err(format!(
+                "unexpected output from {}: {}",
+                self.package(),
+                stdout
+            )),
+        }
+    }
+}
+
+/// Solves a part, then again every time its input or the sources of the
+/// quest or `common` change, until interrupted.
+pub fn watch(root: &Path, store: &Store, quest: u8, options: Options) -> Result<(), String> {
+    let part = options.part.expect("part is checked when parsing");
+    let absolute = |path: &Path| path::absolute(path).map_err(|e| e.to_string());
+
+    let input = match &options.input {
+        Some(path) => absolute(path)?,
+        None => {
+            let dir = absolute(&store.quest_dir(quest))?;
+
+            // notes fetched later should trigger a run too
+            fs::create_dir_all(&dir)
+                .map_err(|e| format!("failed to create `{}`: {}", dir.display(), e))?;
+            dir
+        }
+    };
+
+    let watched = Watched {
+        sources: vec![
+            root.join(format!("quest{:02}", quest)).join("src"),
+            root.join("common").join("src"),
+        ],
+        root: root.to_path_buf(),
+        quest,
+        part,
+        options,
+        input,
+    };
+
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(|e| e.to_string())?;
+
+    for dir in &watched.sources {
+        watcher
+            .watch(dir, RecursiveMode::Recursive)
+            .map_err(|e| format!("failed to watch `{}`: {}", dir.display(), e))?;
+    }
+
+    // files are often replaced rather than written, so watch their directory
+    let input_dir = match watched.options.input {
+        Some(_) => watched.input.parent().unwrap_or(root),
+        None => &watched.input,
+    };
+
+    watcher
+        .watch(input_dir, RecursiveMode::NonRecursive)
+        .map_err(|e| format!("failed to watch `{}`: {}", input_dir.display(), e))?;
+
+    let label = format!("quest {:02} part {}", quest, part.number());
+    let mut previous = None;
+
+    loop {
+        println!("{}: running", label);
+
+        match watched.run(store) {
+            Ok(outcome) => {
+                println!("{}: {}", label, describe(previous.as_ref(), &outcome));
+                previous = Some(outcome);
+            }
+            Err(err) => eprintln!("error: {}", err),
+        }
+
+        println!("watching for changes, ctrl-c to stop\n");
+
+        // wait for a relevant change, then for things to settle
+        loop {
+            let event = events
+                .recv()
+                .map_err(|_| "the watcher stopped".to_string())?
+                .map_err(|e| e.to_string())?;
+
+            if !matches!(event.kind, EventKind::Access(_))
+                && event.paths.iter().any(|p| watched.relevant(p))
+            {
+                break;
+            }
+        }
+
+        while events.recv_timeout(SETTLE).is_ok() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(answer: &str, ms: u64) -> Outcome {
+        Outcome {
+            answer: answer.to_string(),
+            solve: Duration::from_millis(ms),
+        }
+    }
+
+    #[test]
+    fn compares_with_the_previous_run() {
+        let first = outcome("25", 12);
+
+        assert_eq!(describe(None, &first), "25 in 12.0ms");
+        assert_eq!(
+            describe(Some(&first), &outcome("25", 3)),
+            "25 (unchanged) in 3.00ms (was 12.0ms)"
+        );
+        assert_eq!(
+            describe(Some(&first), &outcome("31", 12)),
+            "31 (was 25) in 12.0ms (was 12.0ms)"
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use common::{ParseError, Rng, Solver, parse};
+use std::{
+    cmp::{max, min},
+    fmt::Display,
+};
+
+pub struct Quest {
+    names: Vec<String>,
+    instructions: Vec<i32>,
+}
+
+pub fn parse_file(file: &str) -> Result<Quest, ParseError> {
+    let sections = parse::sections(file);
+
+    let names = sections
+        .first()
+        .map_or("", |names| names.trim())
+        .split(',')
+        .map(|l| l.to_string())
+        .collect();
+
+    let instructions = sections
+        .get(1)
+        .ok_or_else(|| ParseError::new(file, &file[file.len()..], "missing instructions line"))?
+        .split(',')
+        .map(|i| match_instruction(file, i))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Quest {
+        names,
+        instructions,
+    })
+}
+
+fn match_instruction(file: &str, i: &str) -> Result<i32, ParseError> {
+    let value = |digits: &str| {
+        digits
+            .parse::<i32>()
+            .map_err(|_| ParseError::new(file, digits, "failed to parse instruction"))
+    };
+
+    match i {
+        _ if i.starts_with("L") => Ok(-value(&i[1..])?),
+        _ if i.starts_with("R") => value(&i[1..]),
+        _ => Err(ParseError::new(file, i, "invalid instruction format")),
+    }
+}
+
+pub fn part_1(quest: &Quest) -> String {
+    let len: i32 = quest.names.len() as i32;
+
+    let index: usize = quest
+        .instructions
+        .iter()
+        .fold(0, |a, b| min(max(a + b, 0), len - 1))
+        .try_into()
+        .expect("failed to convert i32 to usize");
+
+    quest.names[index].clone()
+}
+
+pub fn part_2(quest: &Quest) -> String {
+    let len: i32 = quest.names.len() as i32;
+
+    let index: usize = quest
+        .instructions
+        .iter()
+        .fold(0, |a, b| ((a + b) % len + len) % len)
+        .try_into()
+        .expect("failed to convert i32 to usize");
+
+    quest.names[index].clone()
+}
+
+pub fn part_3(quest: &Quest) -> String {
+    let len: i32 = quest.names.len() as i32;
+
+    let sorted = quest
+        .instructions
+        .iter()
+        .fold(quest.names.clone(), |mut n, i| {
+            let index = (((i % len) + len) % len) as usize;
+            n.swap(0, index);
+            n
+        });
+
+    sorted[0].clone()
+}
+
+const CONSONANTS: &str = "bdfghklmnprstvxyz";
+const VOWELS: &str = "aeiouy";
+
+fn name(rng: &mut Rng) -> String {
+    let mut name = rng.char(CONSONANTS).to_ascii_uppercase().to_string();
+
+    for i in 0..rng.range(3..=7) {
+        name.push(rng.char(if i % 2 == 0 { VOWELS } else { CONSONANTS }));
+    }
+
+    name
+}
+
+pub struct Quest01;
+
+impl Solver for Quest01 {
+    const QUEST: u8 = 1;
+
+    type Input = Quest;
+
+    fn parse(input: &str) -> Result<Quest, ParseError> {
+        parse_file(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(1);
+        let names = (0..size).map(|_| name(rng)).collect::<Vec<_>>();
+        let instructions = (0..size)
+            .map(|_| format!("{}{}", rng.char("LR"), rng.range(1..=2 * size as i64)))
+            .collect::<Vec<_>>();
+
+        Some(format!("{}\n\n{}", names.join(","), instructions.join(",")))
+    }
+
+    fn part_1(quest: &Quest) -> impl Display {
+        part_1(quest)
+    }
+
+    fn part_2(quest: &Quest) -> impl Display {
+        part_2(quest)
+    }
+
+    fn part_3(quest: &Quest) -> impl Display {
+        part_3(quest)
+    }
+}
+
+common::fixture_tests!(Quest01);
@@ -0,0 +1,147 @@
+use common::{Grid, ParseError, Solver};
+use core::fmt;
+use std::{
+    fmt::Display,
+    ops::{self},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Complex {
+    x: i64,
+    y: i64,
+}
+
+impl Complex {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{},{}]", self.x, self.y)
+    }
+}
+
+impl ops::Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl ops::Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x * rhs.x - self.y * rhs.y,
+            y: self.x * rhs.y + self.y * rhs.x,
+        }
+    }
+}
+
+impl ops::Div for Complex {
+    type Output = Complex;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x / rhs.x,
+            y: self.y / rhs.y,
+        }
+    }
+}
+
+pub fn part_1(a: Complex) -> Complex {
+    let mut r = Complex::new(0, 0);
+
+    for _ in 0..3 {
+        r = r * r;
+        r = r / Complex::new(10, 10);
+        r = r + a;
+    }
+
+    r
+}
+
+/// The engraved points of the 1000 by 1000 square from `origin`, checking
+/// one point every `step`.
+pub fn engraving(origin: Complex, step: usize) -> Grid<bool> {
+    let side = 1_000 / step + 1;
+    let step = step as i64;
+
+    Grid::from_fn(side, side, |p| {
+        is_engraved(origin + Complex::new(p.x * step, p.y * step))
+    })
+}
+
+pub fn part_2(origin: Complex, step: usize) -> usize {
+    engraving(origin, step)
+        .iter()
+        .filter(|(_, engraved)| **engraved)
+        .count()
+}
+
+pub fn is_engraved(a: Complex) -> bool {
+    let mut r = Complex::new(0, 0);
+
+    for _ in 0..100 {
+        r = r * r;
+        r = r / Complex::new(100_000, 100_000);
+        r = r + a;
+
+        if r.x < -1_000_000 || r.x > 1_000_000 || r.y < -1_000_000 || r.y > 1_000_000 {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Notes look like `A=[161,53]`.
+pub fn parse(input: &str) -> Result<Complex, ParseError> {
+    let line = input.trim();
+
+    let (x, y) = line
+        .strip_prefix("A=[")
+        .and_then(|s| s.strip_suffix("]"))
+        .and_then(|s| s.split_once(","))
+        .ok_or_else(|| ParseError::new(input, line, "expected `A=[x,y]`"))?;
+
+    Ok(Complex::new(
+        x.parse()
+            .map_err(|_| ParseError::new(input, x, "failed to parse x"))?,
+        y.parse()
+            .map_err(|_| ParseError::new(input, y, "failed to parse y"))?,
+    ))
+}
+
+pub struct Quest02;
+
+impl Solver for Quest02 {
+    const QUEST: u8 = 2;
+
+    type Input = Complex;
+
+    fn parse(input: &str) -> Result<Complex, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(a: &Complex) -> impl Display {
+        part_1(*a)
+    }
+
+    fn part_2(a: &Complex) -> impl Display {
+        part_2(*a, 10)
+    }
+
+    fn part_3(a: &Complex) -> impl Display {
+        part_2(*a, 1)
+    }
+}
+
+common::fixture_tests!(Quest02);
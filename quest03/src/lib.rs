@@ -0,0 +1,81 @@
+use common::{ParseError, Rng, Solver, parse};
+use std::{collections::HashSet, fmt::Display};
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse::list(input, input, ",", "crate")
+}
+
+pub fn unique(crates: &[i64]) -> Vec<i64> {
+    crates
+        .iter()
+        .copied()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>()
+}
+
+pub fn part_1(crates: &[i64]) -> i64 {
+    unique(crates).iter().sum()
+}
+
+pub fn part_2(crates: &[i64]) -> i64 {
+    let mut sorted = unique(crates);
+    sorted.sort();
+    sorted.as_slice()[0..20].iter().sum()
+}
+
+pub fn part_3(crates: &[i64]) -> i64 {
+    let mut i = 0;
+    let mut crates = crates.to_vec();
+
+    while !crates.is_empty() {
+        for c in unique(&crates) {
+            crates.remove(crates.iter().position(|&el| el == c).unwrap());
+        }
+
+        i += 1;
+    }
+
+    i
+}
+
+pub struct Quest03;
+
+impl Solver for Quest03 {
+    const QUEST: u8 = 3;
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        parse(input)
+    }
+
+    /// Part 2 adds the 20 smallest sizes, so there are always at least 20
+    /// different ones.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut sizes = (1..=100).collect::<Vec<i64>>();
+        rng.shuffle(&mut sizes);
+        sizes.truncate(20);
+
+        sizes.extend((20..size).map(|_| rng.range(1..=100)));
+        rng.shuffle(&mut sizes);
+
+        let sizes = sizes.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        Some(sizes.join(","))
+    }
+
+    fn part_1(crates: &Vec<i64>) -> impl Display {
+        part_1(crates)
+    }
+
+    fn part_2(crates: &Vec<i64>) -> impl Display {
+        part_2(crates)
+    }
+
+    fn part_3(crates: &Vec<i64>) -> impl Display {
+        part_3(crates)
+    }
+}
+
+common::fixture_tests!(Quest03);
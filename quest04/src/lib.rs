@@ -0,0 +1,84 @@
+use common::{ParseError, Rng, Solver, parse};
+use std::fmt::Display;
+
+pub type Gears = Vec<(f64, Option<f64>)>;
+
+pub fn parse(input: &str) -> Result<Gears, ParseError> {
+    parse::lines(input)
+        .map(|s| match s.split_once("|") {
+            Some((a, b)) => Ok((
+                parse::field(input, a, "first gear")?,
+                Some(parse::field(input, b, "second gear")?),
+            )),
+            None => Ok((parse::field(input, s, "gear")?, None)),
+        })
+        .collect()
+}
+
+pub fn left_to_right(gears: &Gears, rotations: f64) -> i64 {
+    gears
+        .windows(2)
+        .fold(rotations, |acc, w| match w {
+            [(a1, a2), (b, _)] => {
+                acc * match (a1, a2) {
+                    (_, Some(x)) => x / b,
+                    (x, None) => x / b,
+                }
+            }
+            _ => panic!("invalid window"),
+        })
+        .floor() as i64
+}
+
+pub fn right_to_left(gears: &Gears, rotations: f64) -> i64 {
+    // same as left_to_right but need to ceil, traverse the windows
+    // in reverse direction and divide instead of mutliply the ratios
+    gears
+        .windows(2)
+        .rev()
+        .fold(rotations, |acc, w| match w {
+            // technically we should check for mounted gears
+            // also when going from right to left but it's
+            // not required for part_2
+            [(a, _), (b, _)] => acc / (a / b),
+            _ => panic!("invalid window"),
+        })
+        .ceil() as i64
+}
+
+pub struct Quest04;
+
+impl Solver for Quest04 {
+    const QUEST: u8 = 4;
+
+    type Input = Gears;
+
+    fn parse(input: &str) -> Result<Gears, ParseError> {
+        parse(input)
+    }
+
+    /// The first and last gears are single, the ones in between are mounted
+    /// pairs.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut gears = vec![rng.range(5..=100).to_string()];
+
+        gears.extend((2..size).map(|_| format!("{}|{}", rng.range(5..=100), rng.range(5..=100))));
+        gears.push(rng.range(5..=100).to_string());
+
+        Some(gears.join("\n"))
+    }
+
+    fn part_1(gears: &Gears) -> impl Display {
+        left_to_right(gears, 2025.0)
+    }
+
+    fn part_2(gears: &Gears) -> impl Display {
+        right_to_left(gears, 10000000000000.0)
+    }
+
+    fn part_3(gears: &Gears) -> impl Display {
+        left_to_right(gears, 100.0)
+    }
+}
+
+common::fixture_tests!(Quest04);
@@ -0,0 +1,90 @@
+pub mod sword;
+
+use crate::sword::Sword;
+use common::{ParseError, Rng, Solver, parse};
+use std::fmt::Display;
+
+fn parse_sword(input: &str, line: &str) -> Result<Sword, ParseError> {
+    let (id, steps) = parse::pair(input, line, ":", "id:steps")?;
+
+    Ok(Sword::from_input(
+        parse::field(input, id, "id")?,
+        parse::list(input, steps, ",", "step")?,
+    ))
+}
+
+pub fn parse_swords(input: &str) -> Result<Vec<Sword>, ParseError> {
+    parse::lines(input)
+        .map(|line| parse_sword(input, line))
+        .collect()
+}
+
+pub fn part_1(swords: &[Sword]) -> i64 {
+    swords.first().expect("no sword").quality()
+}
+
+pub fn part_2(swords: &[Sword]) -> i64 {
+    let mut swords = swords.to_vec();
+
+    swords.sort_by(|a, b| a.cmp_simple(b));
+
+    let (worst, best) = match (swords.first(), swords.last()) {
+        (None, _) | (_, None) => panic!("failed to find min and max"),
+        (Some(a), Some(b)) => (a, b),
+    };
+
+    best.quality() - worst.quality()
+}
+
+pub fn part_3(swords: &[Sword]) -> i64 {
+    let mut swords = swords.to_vec();
+
+    swords.sort_by(|a, b| a.cmp_full(b));
+
+    swords
+        .iter()
+        .map(|sword| sword.id)
+        .rev()
+        .enumerate()
+        .fold(0, |acc, (i, id)| acc + id * ((i + 1) as i64))
+}
+
+pub struct Quest05;
+
+impl Solver for Quest05 {
+    const QUEST: u8 = 5;
+
+    type Input = Vec<Sword>;
+
+    fn parse(input: &str) -> Result<Vec<Sword>, ParseError> {
+        parse_swords(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let swords = (1..=size.max(1))
+            .map(|id| {
+                let steps = (0..rng.range(10..=30))
+                    .map(|_| rng.range(1..=9).to_string())
+                    .collect::<Vec<_>>();
+
+                format!("{}:{}", id, steps.join(","))
+            })
+            .collect::<Vec<_>>();
+
+        Some(swords.join("\n"))
+    }
+
+    fn part_1(swords: &Vec<Sword>) -> impl Display {
+        part_1(swords)
+    }
+
+    fn part_2(swords: &Vec<Sword>) -> impl Display {
+        part_2(swords)
+    }
+
+    fn part_3(swords: &Vec<Sword>) -> impl Display {
+        part_3(swords)
+    }
+}
+
+common::fixture_tests!(Quest05);
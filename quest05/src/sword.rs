@@ -16,10 +16,7 @@ impl Clone for Sword {
             spine: self.spine,
             left: self.left,
             right: self.right,
-            next: match &self.next {
-                Some(n) => Some(Box::new(*n.clone())),
-                _ => None,
-            },
+            next: self.next.clone(),
         }
     }
 }
@@ -132,7 +129,7 @@ impl Sword {
         }
 
         match (&self.next, &rhs.next) {
-            (Some(a), Some(b)) => a.cmp_full(&b),
+            (Some(a), Some(b)) => a.cmp_full(b),
             _ => self.id.cmp(&rhs.id),
         }
     }
@@ -0,0 +1,140 @@
+use common::{ParseError, Part, Rng, Solver};
+use std::{cmp::min, fmt::Display};
+
+pub fn count(people: &[char], mentor: char, novice: char) -> usize {
+    (0..people.len())
+        .filter(|&i| people[i] == mentor)
+        .fold(0, |acc, i| {
+            (i..people.len()).fold(
+                acc,
+                |acc, j| if people[j] == novice { acc + 1 } else { acc },
+            )
+        })
+}
+
+pub fn count_2(
+    people: &[char],
+    mentor: char,
+    novice: char,
+    distance: usize,
+    repeat: usize,
+) -> usize {
+    let len = people.len();
+    let total_len = len * repeat;
+
+    let compute_range = |from: usize, to: usize| {
+        (from..to)
+            .filter(|i| people[*i % len] == mentor)
+            .fold(0, |acc, i| {
+                let start_j = i.saturating_sub(distance);
+                let end_j = min(i + distance, total_len - 1);
+
+                (start_j..=end_j).fold(acc, |acc, j| {
+                    if people[j % len] == novice {
+                        acc + 1
+                    } else {
+                        acc
+                    }
+                })
+            })
+    };
+
+    let mut total = 0;
+
+    // compute the start
+    total += compute_range(0, distance);
+
+    // compute the middle
+    let middle_len = total_len - (distance + distance);
+
+    // compute first segment
+    let segment_total = compute_range(distance, distance + len);
+
+    // count how many times we can fit the people inside
+    // the middle
+    let segment_count = middle_len / len;
+
+    // add that segment segment_count times
+    total += segment_total * segment_count;
+
+    // if middle_len happen to not be divisible by len, compute the remainders
+    let remainder = middle_len % len;
+
+    // compute the remainder
+    total += compute_range(distance + len, distance + len + remainder);
+
+    // compute the end
+    total += compute_range(total_len - distance, total_len);
+
+    total
+}
+
+pub const PAIRS: [(char, char); 3] = [('A', 'a'), ('B', 'b'), ('C', 'c')];
+
+/// Reference for [`count_2`]: lays out the whole repeated row and counts the
+/// novices around each mentor with prefix sums.
+pub fn count_naive(
+    people: &[char],
+    mentor: char,
+    novice: char,
+    distance: usize,
+    repeat: usize,
+) -> usize {
+    let row = people.repeat(repeat);
+    let mut novices = vec![0; row.len() + 1];
+
+    for (i, &c) in row.iter().enumerate() {
+        novices[i + 1] = novices[i] + usize::from(c == novice);
+    }
+
+    (0..row.len())
+        .filter(|&i| row[i] == mentor)
+        .map(|i| novices[min(i + distance + 1, row.len())] - novices[i.saturating_sub(distance)])
+        .sum()
+}
+
+pub struct Quest06;
+
+impl Solver for Quest06 {
+    const QUEST: u8 = 6;
+
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Vec<char>, ParseError> {
+        Ok(input.trim().chars().collect())
+    }
+
+    /// Part 3 needs the 1000 repeated copies to span at least twice its
+    /// distance of 1000, so there are at least two people.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some((0..size.max(2)).map(|_| rng.char("AaBbCc")).collect())
+    }
+
+    fn reference(part: Part) -> Option<fn(&Vec<char>) -> String> {
+        (part == Part::Part3).then_some(|people: &Vec<char>| {
+            let total = PAIRS
+                .iter()
+                .map(|(m, n)| count_naive(people, *m, *n, 1000, 1000));
+
+            total.sum::<usize>().to_string()
+        })
+    }
+
+    fn part_1(people: &Vec<char>) -> impl Display {
+        count(people, 'A', 'a')
+    }
+
+    fn part_2(people: &Vec<char>) -> impl Display {
+        PAIRS
+            .iter()
+            .fold(0, |acc, (m, n)| acc + count(people, *m, *n))
+    }
+
+    fn part_3(people: &Vec<char>) -> impl Display {
+        PAIRS
+            .iter()
+            .fold(0, |acc, (m, n)| acc + count_2(people, *m, *n, 1000, 1000))
+    }
+}
+
+common::fixture_tests!(Quest06);
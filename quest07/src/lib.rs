@@ -0,0 +1,247 @@
+use std::collections::HashSet;
+
+use common::{ParseError, Part, Rng, Solver, graph::Graph, memo::Memo, parse};
+use std::fmt::Display;
+
+pub type Name = String;
+pub type Names = Vec<Name>;
+/// Which letters may follow each letter.
+pub type Rules = Graph<String>;
+
+pub fn parse(input: &str) -> Result<(Names, Rules), ParseError> {
+    let [names, rules] = parse::exact_sections(input, "names and rules")?;
+
+    let names = names
+        .split(",")
+        .map(|s| s.trim().to_string())
+        .collect::<Names>();
+
+    let mut graph = Graph::directed();
+
+    for line in parse::lines(rules) {
+        let (from, to) = parse::pair(input, line, " > ", "a > b,c")?;
+
+        for to in to.split(",") {
+            graph.add_edge(from.to_string(), to.to_string());
+        }
+    }
+
+    Ok((names, graph))
+}
+
+pub fn is_valid(name: &str, rules: &Rules) -> bool {
+    for win in name
+        .chars()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .windows(2)
+    {
+        match win {
+            [a, b] if !rules.has_edge(a.as_str(), b) => {
+                return false;
+            }
+            _ => continue,
+        }
+    }
+
+    true
+}
+
+pub fn filter_invalid_names(names: &[Name], rules: &Rules) -> Vec<(usize, Name)> {
+    names
+        .iter()
+        .cloned()
+        .enumerate()
+        .filter(|(_, name)| is_valid(name, rules))
+        .collect()
+}
+
+pub fn first_valid(names: &[Name], rules: &Rules) -> Option<String> {
+    filter_invalid_names(names, rules)
+        .first()
+        .map(|(_, name)| name.clone())
+}
+
+// Counts the names of at least `min_len` letters that start with a name of
+// `len` letters ending with `last`, itself included. Which letters came
+// before doesn't matter, so that's the key.
+fn count_names(
+    len: usize,
+    last: &str,
+    rules: &Rules,
+    (min_len, max_len): (usize, usize),
+    memo: &mut Memo<(usize, String), usize>,
+) -> usize {
+    memo.get((len, last.to_string()), |memo| {
+        let mut total = usize::from(len >= min_len);
+
+        // only extend the name if it isn't too long
+        if len < max_len {
+            total += rules
+                .neighbours(last)
+                .map(|next| count_names(len + 1, next, rules, (min_len, max_len), memo))
+                .sum::<usize>();
+        }
+
+        total
+    })
+}
+
+pub fn build_names(names: &[Name], rules: &Rules, min_len: usize, max_len: usize) -> usize {
+    let mut memo = Memo::new("quest07 names");
+    let valid = filter_invalid_names(names, rules);
+
+    // names extending another valid name, which are already counted with it
+    let extended = |name: &Name| {
+        name.len() <= max_len
+            && valid
+                .iter()
+                .any(|(_, prefix)| prefix.len() < name.len() && name.starts_with(prefix.as_str()))
+    };
+
+    valid
+        .iter()
+        .map(|(_, name)| name)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .filter(|name| !extended(name))
+        .map(|name| {
+            let last = &name[name.len() - 1..];
+            count_names(name.len(), last, rules, (min_len, max_len), &mut memo)
+        })
+        .sum()
+}
+
+fn extend_naive(
+    name: &mut String,
+    rules: &Rules,
+    min_len: usize,
+    max_len: usize,
+    found: &mut HashSet<Name>,
+) {
+    if name.len() >= min_len {
+        found.insert(name.clone());
+    }
+
+    let last = name[name.len() - 1..].to_string();
+
+    if name.len() < max_len {
+        for c in rules.neighbours(&last) {
+            name.push_str(c);
+            extend_naive(name, rules, min_len, max_len, found);
+            name.pop();
+        }
+    }
+}
+
+/// Reference for [`build_names`]: lists every name instead of counting them
+/// by depth and last letter.
+pub fn build_names_naive(names: &[Name], rules: &Rules, min_len: usize, max_len: usize) -> usize {
+    let mut found = HashSet::new();
+
+    for (_, mut name) in filter_invalid_names(names, rules) {
+        extend_naive(&mut name, rules, min_len, max_len, &mut found);
+    }
+
+    found.len()
+}
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+// Follows the rules from the first letter, or picks letters at random which
+// most likely breaks them.
+fn random_name(rng: &mut Rng, rules: &[(char, Vec<char>)], first: char, valid: bool) -> String {
+    let mut name = first.to_string();
+    let mut last = first;
+
+    for _ in 0..rng.range(2..=8) {
+        let (_, next) = rules
+            .iter()
+            .find(|(from, _)| *from == last)
+            .expect("every letter has rules");
+
+        last = match valid {
+            true => *rng.pick(next),
+            false => rules[rng.below(rules.len())].1[0],
+        };
+        name.push(last);
+    }
+
+    name
+}
+
+pub struct Quest07;
+
+impl Solver for Quest07 {
+    const QUEST: u8 = 7;
+
+    type Input = (Names, Rules);
+
+    fn parse(input: &str) -> Result<(Names, Rules), ParseError> {
+        parse(input)
+    }
+
+    /// Roughly half the names follow the rules, the first one always does.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut letters = LETTERS.chars().collect::<Vec<_>>();
+        rng.shuffle(&mut letters);
+        letters.truncate(8);
+
+        let mut firsts = (0..3)
+            .map(|_| rng.char(LETTERS).to_ascii_uppercase())
+            .collect::<Vec<_>>();
+        firsts.sort();
+        firsts.dedup();
+
+        let rules = firsts
+            .iter()
+            .chain(&letters)
+            .map(|&from| {
+                let mut next = letters.clone();
+                rng.shuffle(&mut next);
+                next.truncate(rng.range(1..=3) as usize);
+                (from, next)
+            })
+            .collect::<Vec<_>>();
+
+        let names = (0..size.max(1))
+            .map(|i| {
+                let first = *rng.pick(&firsts);
+                let valid = i == 0 || rng.chance(0.5);
+                random_name(rng, &rules, first, valid)
+            })
+            .collect::<Vec<_>>();
+
+        let rules = rules
+            .iter()
+            .map(|(from, next)| {
+                let next = next.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+                format!("{} > {}", from, next.join(","))
+            })
+            .collect::<Vec<_>>();
+
+        Some(format!("{}\n\n{}", names.join(","), rules.join("\n")))
+    }
+
+    fn reference(part: Part) -> Option<fn(&(Names, Rules)) -> String> {
+        (part == Part::Part3).then_some(|(names, rules): &(Names, Rules)| {
+            build_names_naive(names, rules, 7, 11).to_string()
+        })
+    }
+
+    fn part_1((names, rules): &(Names, Rules)) -> impl Display {
+        first_valid(names, rules).expect("failed to find a valid name")
+    }
+
+    fn part_2((names, rules): &(Names, Rules)) -> impl Display {
+        filter_invalid_names(names, rules)
+            .iter()
+            .fold(0, |acc, (i, _)| acc + i + 1)
+    }
+
+    fn part_3((names, rules): &(Names, Rules)) -> impl Display {
+        build_names(names, rules, 7, 11)
+    }
+}
+
+common::fixture_tests!(Quest07);
@@ -0,0 +1,218 @@
+use common::{ParseError, Part, Rng, Solver, parse};
+use std::{
+    cmp::{max, min},
+    f64,
+    fmt::Display,
+};
+
+const FLOAT_PRECISION: f64 = 1e-6;
+
+pub type Nail = usize;
+pub type Thread = (Nail, Nail);
+type Point = (f64, f64);
+type Line = (Point, Point);
+
+pub fn parse(input: &str) -> Result<Vec<Thread>, ParseError> {
+    let ordered_nails = input
+        .trim()
+        .split(',')
+        .map(|item| match parse::field::<Nail>(input, item, "nail")? {
+            0 => Err(ParseError::new(
+                input,
+                item.trim(),
+                "nails are numbered from 1",
+            )),
+            nail => Ok(nail),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(to_threads(&ordered_nails))
+}
+
+pub fn to_threads(input: &[Nail]) -> Vec<Thread> {
+    input
+        .windows(2)
+        .collect::<Vec<&[usize]>>()
+        .iter()
+        .map(|slice| match slice {
+            &[a, b] => (*a, *b),
+            _ => panic!("invalid window"),
+        })
+        .collect::<Vec<Thread>>()
+}
+
+// Converts a nail's 1-indexed index to its (y,x) floating point coordinate.
+// Index 1 gives coordinate (-1, 0) and cycles to the right based on the total
+// amount of nails on the circle.
+fn coordinates(nail: Nail, total: usize) -> Point {
+    let angle: f64 = (2.0 * f64::consts::PI / (total as f64)) * ((nail - 1) as f64);
+    (f64::sin(angle), -f64::cos(angle))
+}
+
+/// Threads going straight through the center of a circle of `nails` nails.
+pub fn count_centers(threads: &[Thread], nails: usize) -> usize {
+    threads
+        .iter()
+        .filter(|(a, b)| max(a, b) - min(a, b) == nails / 2)
+        .count()
+}
+
+/// Pairs of threads crossing each other inside the circle.
+pub fn count_knots(threads: &[Thread], nails: usize) -> usize {
+    threads.iter().enumerate().fold(0, |acc, (i, &thread_a)| {
+        acc + threads[0..i]
+            .iter()
+            .to_owned()
+            .filter(|&thread_b| intersects(thread_a, *thread_b, nails))
+            .count()
+    })
+}
+
+/// The most threads a single cut between two of the `knots` nails can go
+/// through.
+pub fn find_max_cuts(threads: &[Thread], knots: usize) -> usize {
+    (0..knots)
+        .flat_map(|i| (i..knots).map(move |j| (i + 1, j + 1)))
+        .filter(|(i, j)| i != j)
+        .collect::<Vec<(usize, usize)>>()
+        .into_iter()
+        .map(|(i, j)| {
+            let mut cuts = threads
+                .iter()
+                .filter(|&other| intersects((i, j), *other, knots))
+                .count();
+
+            if threads.contains(&(i, j)) || threads.contains(&(j, i)) {
+                cuts += 1;
+            }
+
+            cuts
+        })
+        .max()
+        .expect("knots has be to greated than 1")
+}
+
+fn get_intersection((p1, p2): Line, (p3, p4): Line) -> Option<Point> {
+    let (x1, y1) = p1;
+    let (x2, y2) = p2;
+    let (x3, y3) = p3;
+    let (x4, y4) = p4;
+
+    let det = (x1 - x2) * (y3 - y4) - (y1 - y2) * (x3 - x4);
+
+    if det.abs() < 0.0 + FLOAT_PRECISION {
+        // if determinant is zero it means the two
+        // lines are perpendicular and never intersect
+        return None;
+    }
+
+    let px = ((x1 * y2 - y1 * x2) * (x3 - x4) - (x1 - x2) * (x3 * y4 - y3 * x4)) / det;
+    let py = ((x1 * y2 - y1 * x2) * (y3 - y4) - (y1 - y2) * (x3 * y4 - y3 * x4)) / det;
+
+    Some((px, py))
+}
+
+pub fn intersects((nail_a1, nail_a2): Thread, (nail_b1, nail_b2): Thread, knots: usize) -> bool {
+    let point_a1 = coordinates(nail_a1, knots);
+    let point_a2 = coordinates(nail_a2, knots);
+    let point_b1 = coordinates(nail_b1, knots);
+    let point_b2 = coordinates(nail_b2, knots);
+
+    match get_intersection((point_a1, point_a2), (point_b1, point_b2)) {
+        // if norm is 1.0, it means the intersection is on the unit circle,
+        // meaning the two lines share a point and therefore don't intersect
+        Some(p) => norm(p) < (1.0 - FLOAT_PRECISION),
+        None => false,
+    }
+}
+
+// L2 norm
+fn norm((x, y): Point) -> f64 {
+    f64::sqrt(x.powf(2.0) + y.powf(2.0))
+}
+
+/// Reference for [`intersects`] without geometry: two threads cross when
+/// exactly one end of the second lies strictly between the ends of the
+/// first, and they share no nail.
+pub fn crosses((a1, a2): Thread, (b1, b2): Thread) -> bool {
+    let (low, high) = (min(a1, a2), max(a1, a2));
+    let between = |nail| low < nail && nail < high;
+
+    if [b1, b2].iter().any(|&nail| nail == low || nail == high) {
+        return false;
+    }
+
+    between(b1) != between(b2)
+}
+
+pub fn count_knots_naive(threads: &[Thread]) -> usize {
+    (0..threads.len())
+        .flat_map(|i| (0..i).map(move |j| (i, j)))
+        .filter(|&(i, j)| crosses(threads[i], threads[j]))
+        .count()
+}
+
+pub fn find_max_cuts_naive(threads: &[Thread], nails: usize) -> usize {
+    (1..=nails)
+        .flat_map(|i| (i + 1..=nails).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let cuts = threads
+                .iter()
+                .filter(|&&other| crosses((i, j), other))
+                .count();
+            let along = threads.contains(&(i, j)) || threads.contains(&(j, i));
+
+            cuts + usize::from(along)
+        })
+        .max()
+        .expect("there are at least two nails")
+}
+
+pub struct Quest08;
+
+impl Solver for Quest08 {
+    const QUEST: u8 = 8;
+
+    type Input = Vec<Thread>;
+
+    fn parse(input: &str) -> Result<Vec<Thread>, ParseError> {
+        parse(input)
+    }
+
+    /// Nails of the 256 nails circle, never the same twice in a row.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut nails = vec![rng.range(1..=256)];
+
+        while nails.len() < size.max(2) {
+            let nail = rng.range(1..=256);
+
+            if Some(&nail) != nails.last() {
+                nails.push(nail);
+            }
+        }
+
+        let nails = nails.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        Some(nails.join(","))
+    }
+
+    fn reference(part: Part) -> Option<fn(&Vec<Thread>) -> String> {
+        match part {
+            Part::Part1 => None,
+            Part::Part2 => Some(|threads| count_knots_naive(threads).to_string()),
+            Part::Part3 => Some(|threads| find_max_cuts_naive(threads, 256).to_string()),
+        }
+    }
+
+    fn part_1(threads: &Vec<Thread>) -> impl Display {
+        count_centers(threads, 32)
+    }
+
+    fn part_2(threads: &Vec<Thread>) -> impl Display {
+        count_knots(threads, 256)
+    }
+
+    fn part_3(threads: &Vec<Thread>) -> impl Display {
+        find_max_cuts(threads, 256)
+    }
+}
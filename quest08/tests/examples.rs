@@ -0,0 +1,30 @@
+//! The published examples use a circle of 8 nails instead of 32 and 256,
+//! so they can't be fixtures.
+
+use quest08::{count_centers, count_knots, find_max_cuts, parse};
+
+#[test]
+fn part_1() {
+    let threads = parse("1,5,2,6,8,4,1,7,3").unwrap();
+    assert_eq!(count_centers(&threads, 8), 4);
+}
+
+#[test]
+fn part_2() {
+    let threads = parse("1,5,2,6,8,4,1,7,3,5,7,8,2").unwrap();
+    assert_eq!(count_knots(&threads, 8), 21);
+}
+
+#[test]
+fn part_3() {
+    let threads = parse("1,5,2,6,8,4,1,7,3,6").unwrap();
+    assert_eq!(find_max_cuts(&threads, 8), 7);
+}
+
+#[test]
+fn rejects_nail_zero() {
+    let err = parse("1,0,3").unwrap_err();
+
+    assert_eq!(err.message, "nails are numbered from 1");
+    assert_eq!((err.line, err.column), (1, 3));
+}
@@ -0,0 +1,222 @@
+use common::{ParseError, Rng, Solver, graph::Graph, parse};
+use std::fmt::Display;
+
+pub type Dna = (usize, Vec<u128>);
+pub type Person = (usize, Dna);
+
+fn map_char(c: u8) -> u128 {
+    match c {
+        b'A' => 0b0001,
+        b'C' => 0b0010,
+        b'T' => 0b0100,
+        b'G' => 0b1000,
+        _ => panic!("invalid dna sequence"),
+    }
+}
+
+fn get_similarity((len1, dna1): &Dna, (len2, dna2): &Dna, (len3, dna3): &Dna) -> Option<usize> {
+    // bail if the dna aren't all the same size
+    if len1 != len2 || len1 != len3 {
+        return None;
+    }
+
+    let mut score_a = 0;
+    let mut score_b = 0;
+
+    for (c, (p1, p2)) in dna1.iter().zip(dna2.iter().zip(dna3)) {
+        // bail if not related
+        if (c ^ (c & p1)) & (c ^ (c & p2)) != 0 {
+            return None;
+        }
+
+        // start computing the similary score
+        for i in 0..32 {
+            let s = i * 4;
+            let m = 0b1111;
+            let c = (c >> s) & m;
+            let a = (p1 >> s) & m;
+            let b = (p2 >> s) & m;
+
+            if c == 0 || a == 0 || b == 0 {
+                break;
+            }
+
+            if a == c {
+                score_a += 1;
+            }
+
+            if b == c {
+                score_b += 1;
+            }
+        }
+    }
+
+    Some(score_a * score_b)
+}
+
+pub fn parse_dna(input: &str, dna: &str) -> Result<Dna, ParseError> {
+    if let Some((i, c)) = dna.char_indices().find(|(_, c)| !"ACTG".contains(*c)) {
+        return Err(ParseError::new(
+            input,
+            &dna[i..i + c.len_utf8()],
+            "invalid dna sequence",
+        ));
+    }
+
+    let len = dna.len();
+
+    let dna = dna
+        .bytes()
+        .collect::<Vec<u8>>()
+        .chunks(32) // sizeof u128
+        .map(|bytes| {
+            bytes
+                .iter()
+                .enumerate()
+                .fold(0u128, |acc, (i, &byte)| acc + (map_char(byte) << (4 * i)))
+        })
+        .collect::<Vec<u128>>();
+
+    Ok((len, dna))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Person>, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let (id, dna) = parse::pair(input, line, ":", "id:dna")?;
+            Ok((parse::field(input, id, "id")?, parse_dna(input, dna)?))
+        })
+        .collect()
+}
+
+pub fn find_parents((id, dna): &Person, everyone: &[Person]) -> Option<(usize, usize, usize)> {
+    for a in 0..everyone.len() {
+        for b in a..everyone.len() {
+            let (id_a, dna_a) = &everyone[a];
+            let (id_b, dna_b) = &everyone[b];
+
+            if id == id_a || id_b == id {
+                continue;
+            }
+
+            if let Some(score) = get_similarity(dna, dna_a, dna_b) {
+                return Some((*id_a, *id_b, score));
+            }
+        }
+    }
+
+    None
+}
+
+pub fn find_first_relation(people: &[Person]) -> Option<(usize, usize, usize, usize)> {
+    for child in people {
+        if let Some((id_a, id_b, score)) = find_parents(child, people) {
+            let &(id, _) = child;
+            return Some((id, id_a, id_b, score));
+        }
+    }
+
+    None
+}
+
+pub fn compute_similarities(everyone: &[Person]) -> usize {
+    let mut total = 0;
+
+    for child in everyone {
+        if let Some((_, _, score)) = find_parents(child, everyone) {
+            total += score;
+        }
+    }
+
+    total
+}
+
+/// Links every child to both of their parents.
+pub fn build_graph(everyone: &[Person]) -> Graph<usize> {
+    let mut relations = Graph::undirected();
+
+    for person in everyone {
+        if let Some((id_a, id_b, _)) = find_parents(person, everyone) {
+            let &(id, _) = person;
+            relations.add_edge(id, id_a);
+            relations.add_edge(id, id_b);
+        }
+    }
+
+    relations
+}
+
+/// Sum of the ids of the largest connected family.
+pub fn find_biggest_family(relations: &Graph<usize>) -> usize {
+    relations
+        .components()
+        .into_iter()
+        .max_by_key(|family| family.len())
+        .expect("graph is empty")
+        .into_iter()
+        .sum()
+}
+
+const DNA_LENGTH: usize = 128;
+
+pub struct Quest09;
+
+impl Solver for Quest09 {
+    const QUEST: u8 = 9;
+
+    type Input = Vec<Person>;
+
+    fn parse(input: &str) -> Result<Vec<Person>, ParseError> {
+        parse(input)
+    }
+
+    /// A third of the people are random, everyone else is the child of two
+    /// people listed before them.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(3);
+        let mut people: Vec<Vec<u8>> = vec![];
+
+        for i in 0..size {
+            let dna = match i < (size / 3).max(2) {
+                true => (0..DNA_LENGTH).map(|_| rng.char("ACGT") as u8).collect(),
+                false => {
+                    let a = rng.below(people.len());
+                    let b = (a + 1 + rng.below(people.len() - 1)) % people.len();
+
+                    (0..DNA_LENGTH)
+                        .map(|j| match rng.chance(0.5) {
+                            true => people[a][j],
+                            false => people[b][j],
+                        })
+                        .collect()
+                }
+            };
+
+            people.push(dna);
+        }
+
+        let people = people
+            .iter()
+            .enumerate()
+            .map(|(i, dna)| format!("{}:{}", i + 1, String::from_utf8_lossy(dna)))
+            .collect::<Vec<_>>();
+
+        Some(people.join("\n"))
+    }
+
+    fn part_1(people: &Vec<Person>) -> impl Display {
+        let (_, _, _, score) = find_first_relation(people).expect("failed to find a valid child");
+        score
+    }
+
+    fn part_2(people: &Vec<Person>) -> impl Display {
+        compute_similarities(people)
+    }
+
+    fn part_3(people: &Vec<Person>) -> impl Display {
+        let graph = build_graph(people);
+        find_biggest_family(&graph)
+    }
+}
+
+common::fixture_tests!(Quest09);